@@ -1,4 +1,6 @@
-use std::rc::Rc;
+use std::fmt;
+
+use linked_list::deque::Deque;
 
 struct CircularBuffer<T> {
     buffer: Vec<Option<T>>,
@@ -8,33 +10,32 @@ struct CircularBuffer<T> {
     usage: usize,
 }
 
-
-impl<T> CircularBuffer<T> {
+impl<T: fmt::Debug> CircularBuffer<T> {
     fn new(capacity: usize) -> CircularBuffer<T> {
         CircularBuffer {
-            buffer: vec![None; capacity],
+            // `vec![None; n]` would need `T: Clone`.
+            buffer: (0..capacity).map(|_| None).collect(),
             read: 0,
             write: 0,
-            capacity: capacity,
+            capacity,
             usage: 0,
         }
     }
 
     fn write(&mut self, value: T) {
         println!("Write index: {}", self.write);
-        let wrapped_val = Some(value);
 
         if self.is_full() {
-            self.buffer[self.write] = wrapped_val;
             println!(
                 "Buffer is full, overwriting the earlier element with {:?}",
                 value
             );
+            self.buffer[self.write] = Some(value);
         } else {
-            self.buffer[self.write] = wrapped_val;
+            println!("Written value: {:?}", value);
+            self.buffer[self.write] = Some(value);
             self.write = (self.write + 1) % self.capacity;
             self.usage += 1;
-            println!("Written value: {:?}", value);
         }
     }
 
@@ -42,6 +43,7 @@ impl<T> CircularBuffer<T> {
         println!("Read index: {}", self.read);
         if self.is_empty() {
             println!("Buffer is empty");
+            return;
         }
         let value = self.buffer[self.read].take().unwrap();
         self.read = (self.read + 1) % self.capacity;
@@ -69,12 +71,79 @@ impl<T> CircularBuffer<T> {
     }
 }
 
+impl<T> CircularBuffer<T> {
+    fn step_back(&self, index: usize) -> usize {
+        (index + self.capacity - 1) % self.capacity
+    }
+}
+
+// As a deque the buffer grows from either end of the ring. Pushing onto a
+// full buffer overwrites the element at the opposite end.
+impl<T> Deque for CircularBuffer<T> {
+    type Item = T;
 
-struct Superman {
-    name: String,
-    age: u8,
-    // random rc reference
-    rc: std::rc::Rc<String>,
+    fn push_front(&mut self, value: T) {
+        self.read = self.step_back(self.read);
+        self.buffer[self.read] = Some(value);
+        if self.usage == self.capacity {
+            self.write = self.read;
+        } else {
+            self.usage += 1;
+        }
+    }
+
+    fn push_back(&mut self, value: T) {
+        self.buffer[self.write] = Some(value);
+        self.write = (self.write + 1) % self.capacity;
+        if self.usage == self.capacity {
+            self.read = self.write;
+        } else {
+            self.usage += 1;
+        }
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        if self.usage == 0 {
+            return None;
+        }
+        let value = self.buffer[self.read].take();
+        self.read = (self.read + 1) % self.capacity;
+        self.usage -= 1;
+        value
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        if self.usage == 0 {
+            return None;
+        }
+        self.write = self.step_back(self.write);
+        self.usage -= 1;
+        self.buffer[self.write].take()
+    }
+
+    fn peek_front_with<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        if self.usage == 0 {
+            return None;
+        }
+        self.buffer[self.read].as_ref().map(f)
+    }
+
+    fn peek_back_with<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        if self.usage == 0 {
+            return None;
+        }
+        self.buffer[self.step_back(self.write)].as_ref().map(f)
+    }
+
+    fn len(&self) -> usize {
+        self.usage
+    }
 }
 
 fn main() {
@@ -100,4 +169,41 @@ fn main() {
 
     buffer.read();
     buffer.display();
+
+    buffer.push_front(0);
+    println!(
+        "Front: {:?}, back: {:?}",
+        buffer.peek_front(),
+        buffer.peek_back()
+    );
+    println!("Popped back: {:?}", buffer.pop_back());
+}
+
+#[cfg(test)]
+mod tests {
+    use linked_list::conformance::{self, Subject};
+
+    use super::CircularBuffer;
+
+    impl Subject for CircularBuffer<u32> {
+        fn with_capacity(capacity: usize) -> Self {
+            CircularBuffer::new(capacity)
+        }
+
+        fn capacity(&self) -> Option<usize> {
+            Some(self.capacity)
+        }
+
+        fn get(&self, index: usize) -> Option<u32> {
+            if index >= self.usage {
+                return None;
+            }
+            self.buffer[(self.read + index) % self.capacity]
+        }
+    }
+
+    #[test]
+    fn conforms_to_vec_deque() {
+        conformance::check_all::<CircularBuffer<u32>>();
+    }
 }
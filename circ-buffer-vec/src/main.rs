@@ -1,3 +1,5 @@
+use linked_list::deque::Deque;
+
 struct CircularBuffer {
     buffer: Vec<Option<i32>>,
     read: usize,
@@ -12,7 +14,7 @@ impl CircularBuffer {
             buffer: vec![None; capacity],
             read: 0,
             write: 0,
-            capacity: capacity,
+            capacity,
             usage: 0,
         }
     }
@@ -39,6 +41,7 @@ impl CircularBuffer {
         println!("Read index: {}", self.read);
         if self.is_empty() {
             println!("Buffer is empty");
+            return;
         }
         let value = self.buffer[self.read].unwrap();
         self.buffer[self.read] = None;
@@ -48,30 +51,96 @@ impl CircularBuffer {
     }
 
     fn is_empty(&self) -> bool {
-        if (self.usage == 0 && self.buffer[self.read] == None) {
-            return true;
-        }
-        return false;
+        self.usage == 0 && self.buffer[self.read].is_none()
     }
 
     fn is_full(&self) -> bool {
-        if (self.usage == self.capacity) {
-            return true;
-        }
-        return false;
+        self.usage == self.capacity
     }
 
-    fn display(&self) { 
+    fn display(&self) {
         for i in 0..self.capacity {
             if let Some(value) = self.buffer[i] {
                 print!("{} ", value);
-            }
-            else {
+            } else {
                 print!("[ ] ");
             }
         }
         println!();
     }
+
+    fn step_back(&self, index: usize) -> usize {
+        (index + self.capacity - 1) % self.capacity
+    }
+}
+
+// As a deque the buffer grows from either end of the ring. Pushing onto a
+// full buffer overwrites the element at the opposite end.
+impl Deque for CircularBuffer {
+    type Item = i32;
+
+    fn push_front(&mut self, value: i32) {
+        self.read = self.step_back(self.read);
+        self.buffer[self.read] = Some(value);
+        if self.usage == self.capacity {
+            self.write = self.read;
+        } else {
+            self.usage += 1;
+        }
+    }
+
+    fn push_back(&mut self, value: i32) {
+        self.buffer[self.write] = Some(value);
+        self.write = (self.write + 1) % self.capacity;
+        if self.usage == self.capacity {
+            self.read = self.write;
+        } else {
+            self.usage += 1;
+        }
+    }
+
+    fn pop_front(&mut self) -> Option<i32> {
+        if self.usage == 0 {
+            return None;
+        }
+        let value = self.buffer[self.read].take();
+        self.read = (self.read + 1) % self.capacity;
+        self.usage -= 1;
+        value
+    }
+
+    fn pop_back(&mut self) -> Option<i32> {
+        if self.usage == 0 {
+            return None;
+        }
+        self.write = self.step_back(self.write);
+        self.usage -= 1;
+        self.buffer[self.write].take()
+    }
+
+    fn peek_front_with<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&i32) -> R,
+    {
+        if self.usage == 0 {
+            return None;
+        }
+        self.buffer[self.read].as_ref().map(f)
+    }
+
+    fn peek_back_with<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&i32) -> R,
+    {
+        if self.usage == 0 {
+            return None;
+        }
+        self.buffer[self.step_back(self.write)].as_ref().map(f)
+    }
+
+    fn len(&self) -> usize {
+        self.usage
+    }
 }
 
 fn main() {
@@ -89,7 +158,7 @@ fn main() {
 
     buffer.read();
     buffer.read();
-    
+
     buffer.display();
 
     buffer.write(7);
@@ -97,4 +166,55 @@ fn main() {
 
     buffer.read();
     buffer.display();
+
+    buffer.push_front(0);
+    println!(
+        "Front: {:?}, back: {:?}",
+        buffer.peek_front(),
+        buffer.peek_back()
+    );
+    println!("Popped back: {:?}", buffer.pop_back());
+}
+
+#[cfg(test)]
+mod tests {
+    use linked_list::conformance::{self, Subject};
+
+    use super::CircularBuffer;
+
+    impl Subject for CircularBuffer {
+        fn with_capacity(capacity: usize) -> Self {
+            CircularBuffer::new(capacity)
+        }
+
+        fn capacity(&self) -> Option<usize> {
+            Some(self.capacity)
+        }
+
+        fn get(&self, index: usize) -> Option<i32> {
+            if index >= self.usage {
+                return None;
+            }
+            self.buffer[(self.read + index) % self.capacity]
+        }
+    }
+
+    #[test]
+    fn conforms_to_vec_deque() {
+        conformance::check_all::<CircularBuffer>();
+    }
+
+    #[test]
+    fn read_on_an_empty_buffer_leaves_it_unchanged() {
+        let mut buffer = CircularBuffer::new(3);
+        buffer.read();
+        assert!(buffer.is_empty());
+        assert_eq!((buffer.read, buffer.write, buffer.usage), (0, 0, 0));
+
+        buffer.write(1);
+        buffer.read();
+        buffer.read();
+        assert!(buffer.is_empty());
+        assert_eq!((buffer.read, buffer.write, buffer.usage), (1, 1, 0));
+    }
 }
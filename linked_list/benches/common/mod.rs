@@ -0,0 +1,18 @@
+// Shared by the benches; each one pulls it in with `mod common;`.
+
+use std::time::Instant;
+
+const RUNS: u32 = 5;
+
+// Runs `f` a few times and reports the best time per operation.
+pub fn measure<F: FnMut() -> u32>(name: &str, mut f: F) {
+    let mut best = f64::MAX;
+    let mut ops = 0;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        ops = f();
+        let elapsed = start.elapsed().as_nanos() as f64;
+        best = best.min(elapsed / ops as f64);
+    }
+    println!("{:<44} {:>10.1} ns/op ({} ops)", name, best, ops);
+}
@@ -0,0 +1,109 @@
+// Compares the `Option`-linked `DoublyLinkedList` with the sentinel-ring
+// `SentinelList`. Run with `cargo bench --bench sentinel`.
+
+mod common;
+
+use std::hint::black_box;
+
+use common::measure;
+use linked_list::{linked_list::DoublyLinkedList, sentinel_list::SentinelList};
+
+const SIZES: [u32; 3] = [1_000, 10_000, 100_000];
+
+fn bench_option_list(n: u32) {
+    measure(&format!("option   append+pop_last  n={}", n), || {
+        let mut list = DoublyLinkedList::new();
+        for i in 0..n {
+            list.append(i);
+        }
+        while let Some(value) = list.pop_last() {
+            black_box(value);
+        }
+        n * 2
+    });
+
+    measure(&format!("option   insert_to front  n={}", n), || {
+        let mut list = DoublyLinkedList::new();
+        list.append(0);
+        for i in 1..n {
+            list.insert_to(0, i).unwrap();
+        }
+        n
+    });
+
+    let middle_ops = n.min(2_000);
+    measure(&format!("option   insert+pop middle n={}", n), || {
+        let mut list = DoublyLinkedList::new();
+        for i in 0..n {
+            list.append(i);
+        }
+        for i in 0..middle_ops {
+            list.insert_to(list.len() / 2, i).unwrap();
+            black_box(list.pop_at(list.len() / 2).unwrap());
+        }
+        middle_ops * 2
+    });
+
+    let mut list = DoublyLinkedList::new();
+    for i in 0..n {
+        list.append(i as u64);
+    }
+    measure(&format!("option   iterate          n={}", n), || {
+        let sum: u64 = list.iter().map(|node| node.borrow().value.unwrap()).sum();
+        black_box(sum);
+        n
+    });
+}
+
+fn bench_sentinel_list(n: u32) {
+    measure(&format!("sentinel append+pop_last  n={}", n), || {
+        let mut list = SentinelList::new();
+        for i in 0..n {
+            list.append(i);
+        }
+        while let Some(value) = list.pop_last() {
+            black_box(value);
+        }
+        n * 2
+    });
+
+    measure(&format!("sentinel insert_to front  n={}", n), || {
+        let mut list = SentinelList::new();
+        list.append(0);
+        for i in 1..n {
+            list.insert_to(0, i).unwrap();
+        }
+        n
+    });
+
+    let middle_ops = n.min(2_000);
+    measure(&format!("sentinel insert+pop middle n={}", n), || {
+        let mut list = SentinelList::new();
+        for i in 0..n {
+            list.append(i);
+        }
+        for i in 0..middle_ops {
+            list.insert_to(list.len() / 2, i).unwrap();
+            black_box(list.pop_at(list.len() / 2).unwrap());
+        }
+        middle_ops * 2
+    });
+
+    let mut list = SentinelList::new();
+    for i in 0..n {
+        list.append(i as u64);
+    }
+    measure(&format!("sentinel iterate          n={}", n), || {
+        let sum: u64 = list.iter().map(|node| node.borrow().value.unwrap()).sum();
+        black_box(sum);
+        n
+    });
+}
+
+fn main() {
+    for n in SIZES {
+        bench_option_list(n);
+        bench_sentinel_list(n);
+        println!();
+    }
+}
@@ -0,0 +1,135 @@
+// Compares `UnrolledList` with the one-element-per-node `DoublyLinkedList`,
+// using `Vec` as the baseline. The raw-pointer list lives in
+// `perf-tester/unsafe-linkedlist`, whose `main` prints the same comparison
+// against it. Run with `cargo bench --bench unrolled`.
+
+mod common;
+
+use std::hint::black_box;
+
+use common::measure;
+use linked_list::{linked_list::DoublyLinkedList, unrolled_list::UnrolledList};
+
+const SIZES: [u32; 3] = [1_000, 10_000, 100_000];
+
+fn bench_rc_list(n: u32) {
+    measure(&format!("rc           append+pop_last  n={}", n), || {
+        let mut list = DoublyLinkedList::new();
+        for i in 0..n {
+            list.append(i);
+        }
+        while let Some(value) = list.pop_last() {
+            black_box(value);
+        }
+        n * 2
+    });
+
+    let middle_ops = n.min(2_000);
+    measure(&format!("rc           insert+pop middle n={}", n), || {
+        let mut list = DoublyLinkedList::new();
+        for i in 0..n {
+            list.append(i);
+        }
+        for i in 0..middle_ops {
+            list.insert_to(list.len() / 2, i).unwrap();
+            black_box(list.pop_at(list.len() / 2).unwrap());
+        }
+        middle_ops * 2
+    });
+
+    let mut list = DoublyLinkedList::new();
+    for i in 0..n {
+        list.append(i as u64);
+    }
+    measure(&format!("rc           iterate          n={}", n), || {
+        let sum: u64 = list.iter().map(|node| node.borrow().value.unwrap()).sum();
+        black_box(sum);
+        n
+    });
+}
+
+fn bench_unrolled_list<const N: usize>(n: u32) {
+    let label = format!("unrolled<{}>", N);
+
+    measure(&format!("{:<12} append+pop_last  n={}", label, n), || {
+        let mut list: UnrolledList<u32, N> = UnrolledList::default();
+        for i in 0..n {
+            list.append(i);
+        }
+        while let Some(value) = list.pop_last() {
+            black_box(value);
+        }
+        n * 2
+    });
+
+    let middle_ops = n.min(2_000);
+    measure(&format!("{:<12} insert+pop middle n={}", label, n), || {
+        let mut list: UnrolledList<u32, N> = UnrolledList::default();
+        for i in 0..n {
+            list.append(i);
+        }
+        for i in 0..middle_ops {
+            list.insert_to(list.len() / 2, i).unwrap();
+            black_box(list.pop_at(list.len() / 2).unwrap());
+        }
+        middle_ops * 2
+    });
+
+    let mut list: UnrolledList<u64, N> = UnrolledList::default();
+    for i in 0..n {
+        list.append(i as u64);
+    }
+    measure(&format!("{:<12} iterate          n={}", label, n), || {
+        let sum: u64 = list.iter().map(|item| *item.borrow()).sum();
+        black_box(sum);
+        n
+    });
+    measure(&format!("{:<12} iterate by node  n={}", label, n), || {
+        let sum: u64 = list
+            .nodes()
+            .map(|node| node.borrow().values().sum::<u64>())
+            .sum();
+        black_box(sum);
+        n
+    });
+}
+
+fn bench_vec(n: u32) {
+    measure(&format!("vec          append+pop_last  n={}", n), || {
+        let mut vec = Vec::new();
+        for i in 0..n {
+            vec.push(i);
+        }
+        while let Some(value) = vec.pop() {
+            black_box(value);
+        }
+        n * 2
+    });
+
+    let middle_ops = n.min(2_000);
+    measure(&format!("vec          insert+pop middle n={}", n), || {
+        let mut vec: Vec<u32> = (0..n).collect();
+        for i in 0..middle_ops {
+            vec.insert(vec.len() / 2, i);
+            black_box(vec.remove(vec.len() / 2));
+        }
+        middle_ops * 2
+    });
+
+    let vec: Vec<u64> = (0..n as u64).collect();
+    measure(&format!("vec          iterate          n={}", n), || {
+        let sum: u64 = vec.iter().sum();
+        black_box(sum);
+        n
+    });
+}
+
+fn main() {
+    for n in SIZES {
+        bench_rc_list(n);
+        bench_unrolled_list::<16>(n);
+        bench_unrolled_list::<64>(n);
+        bench_vec(n);
+        println!();
+    }
+}
@@ -0,0 +1,886 @@
+use std::{
+    cmp, fmt, mem,
+    ops::RangeBounds,
+    sync::atomic::{AtomicU32, Ordering as AtomicOrdering},
+};
+
+use crate::conformance::Subject;
+use crate::deque::Deque;
+use crate::linked_list::{self, ListError};
+use crate::memory::{self, MemoryUsage};
+use crate::random::XorShift;
+
+// A doubly linked list whose nodes all live in one `Vec`. Links are
+// `NodeId`s (slot index + generation) instead of pointers, so there is no
+// allocation per node, no reference counting and no `unsafe`. Freed slots go
+// on a free list and are reused by later inserts.
+//
+// Every time a slot is freed its generation goes up, so a `NodeId` kept
+// around after its node was removed no longer matches and is reported as
+// stale instead of silently pointing at whatever reused the slot. Each list
+// also stamps its ids with its own number, so an id from another list is
+// rejected rather than read as an index into this one.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId {
+    list: u32,
+    index: u32,
+    generation: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandleError {
+    // The node has been removed from the list.
+    Stale(NodeId),
+    // The id was handed out by a different list.
+    Foreign(NodeId),
+}
+
+impl fmt::Display for HandleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HandleError::Stale(id) => write!(
+                f,
+                "node {} (generation {}) has been removed",
+                id.index, id.generation
+            ),
+            HandleError::Foreign(id) => {
+                write!(f, "node {} belongs to another list", id.index)
+            }
+        }
+    }
+}
+
+impl std::error::Error for HandleError {}
+
+static NEXT_LIST: AtomicU32 = AtomicU32::new(0);
+
+struct ArenaNode<T> {
+    value: T,
+    prev: Option<NodeId>,
+    next: Option<NodeId>,
+}
+
+enum Entry<T> {
+    Occupied(ArenaNode<T>),
+    Free { next_free: Option<u32> },
+}
+
+struct Slot<T> {
+    generation: u32,
+    entry: Entry<T>,
+}
+
+pub struct ArenaList<T> {
+    id: u32,
+    slots: Vec<Slot<T>>,
+    free: Option<u32>,
+    count: u32,
+    head: Option<NodeId>,
+    tail: Option<NodeId>,
+}
+
+pub struct ArenaIter<'a, T> {
+    list: &'a ArenaList<T>,
+    front: Option<NodeId>,
+    back: Option<NodeId>,
+    remaining: u32,
+}
+
+impl<'a, T> Iterator for ArenaIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let node = self.list.linked(self.front?);
+        self.front = node.next;
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining as usize;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for ArenaIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let node = self.list.linked(self.back?);
+        self.back = node.prev;
+        Some(&node.value)
+    }
+}
+
+impl<T> ExactSizeIterator for ArenaIter<'_, T> {}
+
+// Owns the values taken out by `ArenaList::drain` (or a whole list, for
+// `into_iter`); they have already left the source list, so dropping a partly
+// consumed `ArenaDrain` just frees whatever is left.
+pub struct ArenaDrain<T> {
+    list: ArenaList<T>,
+}
+
+impl<T> Iterator for ArenaDrain<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.count as usize;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for ArenaDrain<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_last()
+    }
+}
+
+impl<T> ExactSizeIterator for ArenaDrain<T> {}
+
+impl<T> Default for ArenaList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> ArenaList<T> {
+    pub fn new() -> Self {
+        ArenaList {
+            id: NEXT_LIST.fetch_add(1, AtomicOrdering::Relaxed),
+            slots: Vec::new(),
+            free: None,
+            count: 0,
+            head: None,
+            tail: None,
+        }
+    }
+
+    // Room for `capacity` nodes before the arena has to grow.
+    pub fn with_capacity(capacity: usize) -> Self {
+        ArenaList {
+            slots: Vec::with_capacity(capacity),
+            ..Self::new()
+        }
+    }
+
+    pub fn len(&self) -> u32 {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    pub fn iter(&self) -> ArenaIter<'_, T> {
+        ArenaIter {
+            list: self,
+            front: self.head,
+            back: self.tail,
+            remaining: self.count,
+        }
+    }
+
+    pub fn get(&self, index: u32) -> Result<&T, ListError> {
+        let id = self.node_at(index)?;
+        Ok(&self.linked(id).value)
+    }
+
+    pub fn get_mut(&mut self, index: u32) -> Result<&mut T, ListError> {
+        let id = self.node_at(index)?;
+        Ok(&mut self.linked_mut(id).value)
+    }
+
+    pub fn first(&self) -> Result<&T, ListError> {
+        self.get(0)
+    }
+
+    pub fn last(&self) -> Result<&T, ListError> {
+        self.get(self.count.saturating_sub(1))
+    }
+
+    pub fn set(&mut self, index: u32, value: T) -> Result<T, ListError> {
+        Ok(mem::replace(self.get_mut(index)?, value))
+    }
+
+    pub fn swap(&mut self, a: u32, b: u32) -> Result<(), ListError> {
+        let a = self.node_at(a)?;
+        let b = self.node_at(b)?;
+        if a == b {
+            return Ok(());
+        }
+
+        let (low, high) = if a.index < b.index { (a, b) } else { (b, a) };
+        let (left, right) = self.slots.split_at_mut(high.index as usize);
+        match (&mut left[low.index as usize].entry, &mut right[0].entry) {
+            (Entry::Occupied(low), Entry::Occupied(high)) => {
+                mem::swap(&mut low.value, &mut high.value)
+            }
+            _ => unreachable!("Linked node in a free slot"),
+        }
+        Ok(())
+    }
+
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|item| item == value)
+    }
+
+    pub fn position<P>(&self, predicate: P) -> Option<u32>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().position(predicate).map(|index| index as u32)
+    }
+
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    pub fn append(&mut self, value: T) -> NodeId {
+        self.link_between(self.tail, None, value)
+    }
+
+    pub fn push_front(&mut self, value: T) -> NodeId {
+        self.link_between(None, self.head, value)
+    }
+
+    pub fn pop_last(&mut self) -> Option<T> {
+        let tail = self.tail?;
+        Some(self.unlink(tail))
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head = self.head?;
+        Some(self.unlink(head))
+    }
+
+    pub fn insert_to(&mut self, index: u32, value: T) -> Result<NodeId, ListError> {
+        let next = self.node_at(index)?;
+        let prev = self.linked(next).prev;
+        Ok(self.link_between(prev, Some(next), value))
+    }
+
+    pub fn pop_at(&mut self, index: u32) -> Result<T, ListError> {
+        let id = self.node_at(index)?;
+        Ok(self.unlink(id))
+    }
+
+    // Handle-based access. A handle stays valid, and keeps pointing at the
+    // same element, until that element is removed.
+
+    pub fn front_id(&self) -> Option<NodeId> {
+        self.head
+    }
+
+    pub fn back_id(&self) -> Option<NodeId> {
+        self.tail
+    }
+
+    pub fn node_id(&self, index: u32) -> Result<NodeId, ListError> {
+        self.node_at(index)
+    }
+
+    pub fn is_valid(&self, id: NodeId) -> bool {
+        self.node(id).is_ok()
+    }
+
+    pub fn get_by_id(&self, id: NodeId) -> Result<&T, HandleError> {
+        self.node(id).map(|node| &node.value)
+    }
+
+    pub fn get_by_id_mut(&mut self, id: NodeId) -> Result<&mut T, HandleError> {
+        self.node_mut(id).map(|node| &mut node.value)
+    }
+
+    pub fn next_id(&self, id: NodeId) -> Result<Option<NodeId>, HandleError> {
+        self.node(id).map(|node| node.next)
+    }
+
+    pub fn prev_id(&self, id: NodeId) -> Result<Option<NodeId>, HandleError> {
+        self.node(id).map(|node| node.prev)
+    }
+
+    pub fn insert_before(&mut self, id: NodeId, value: T) -> Result<NodeId, HandleError> {
+        let prev = self.node(id)?.prev;
+        Ok(self.link_between(prev, Some(id), value))
+    }
+
+    pub fn insert_after(&mut self, id: NodeId, value: T) -> Result<NodeId, HandleError> {
+        let next = self.node(id)?.next;
+        Ok(self.link_between(Some(id), next, value))
+    }
+
+    pub fn remove(&mut self, id: NodeId) -> Result<T, HandleError> {
+        self.node(id)?;
+        Ok(self.unlink(id))
+    }
+
+    // `allocated` counts every slot in the arena, free ones included.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        let slot_bytes = mem::size_of::<Slot<T>>();
+        let breakdown = vec![
+            ("value", mem::size_of::<T>()),
+            ("prev: Option<NodeId>", mem::size_of::<Option<NodeId>>()),
+            ("next: Option<NodeId>", mem::size_of::<Option<NodeId>>()),
+            ("generation: u32", mem::size_of::<u32>()),
+        ];
+
+        MemoryUsage {
+            name: "ArenaList<T>".to_owned(),
+            elements: self.count as usize,
+            allocated: self.slots.capacity(),
+            header_bytes: mem::size_of::<Self>(),
+            bytes_per_slot: slot_bytes,
+            breakdown: memory::with_padding(breakdown, slot_bytes),
+        }
+    }
+
+    fn node(&self, id: NodeId) -> Result<&ArenaNode<T>, HandleError> {
+        if id.list != self.id {
+            return Err(HandleError::Foreign(id));
+        }
+        match self.slots.get(id.index as usize) {
+            Some(Slot {
+                generation,
+                entry: Entry::Occupied(node),
+            }) if *generation == id.generation => Ok(node),
+            _ => Err(HandleError::Stale(id)),
+        }
+    }
+
+    fn node_mut(&mut self, id: NodeId) -> Result<&mut ArenaNode<T>, HandleError> {
+        if id.list != self.id {
+            return Err(HandleError::Foreign(id));
+        }
+        match self.slots.get_mut(id.index as usize) {
+            Some(Slot {
+                generation,
+                entry: Entry::Occupied(node),
+            }) if *generation == id.generation => Ok(node),
+            _ => Err(HandleError::Stale(id)),
+        }
+    }
+
+    // For ids taken from the list's own links, which are always valid.
+    fn linked(&self, id: NodeId) -> &ArenaNode<T> {
+        self.node(id).expect("Link to a freed node")
+    }
+
+    fn linked_mut(&mut self, id: NodeId) -> &mut ArenaNode<T> {
+        self.node_mut(id).expect("Link to a freed node")
+    }
+
+    // Walks from whichever end is closer to `index`.
+    fn node_at(&self, index: u32) -> Result<NodeId, ListError> {
+        if index >= self.count {
+            return Err(ListError::IndexOutOfBounds {
+                index,
+                len: self.count,
+            });
+        }
+
+        let found = if index <= self.count / 2 {
+            let mut cursor = self.head;
+            for _ in 0..index {
+                cursor = cursor.and_then(|id| self.linked(id).next);
+            }
+            cursor
+        } else {
+            let mut cursor = self.tail;
+            for _ in index..self.count - 1 {
+                cursor = cursor.and_then(|id| self.linked(id).prev);
+            }
+            cursor
+        };
+        Ok(found.expect("List shorter than its length"))
+    }
+
+    fn link_between(&mut self, prev: Option<NodeId>, next: Option<NodeId>, value: T) -> NodeId {
+        let id = self.allocate(ArenaNode { value, prev, next });
+
+        match prev {
+            Some(prev) => self.linked_mut(prev).next = Some(id),
+            None => self.head = Some(id),
+        }
+        match next {
+            Some(next) => self.linked_mut(next).prev = Some(id),
+            None => self.tail = Some(id),
+        }
+        self.count += 1;
+        id
+    }
+
+    fn unlink(&mut self, id: NodeId) -> T {
+        let node = self.release(id);
+
+        match node.prev {
+            Some(prev) => self.linked_mut(prev).next = node.next,
+            None => self.head = node.next,
+        }
+        match node.next {
+            Some(next) => self.linked_mut(next).prev = node.prev,
+            None => self.tail = node.prev,
+        }
+        self.count -= 1;
+        node.value
+    }
+
+    fn allocate(&mut self, node: ArenaNode<T>) -> NodeId {
+        let Some(index) = self.free else {
+            let index = u32::try_from(self.slots.len()).expect("Arena is full");
+            self.slots.push(Slot {
+                generation: 0,
+                entry: Entry::Occupied(node),
+            });
+            return NodeId {
+                list: self.id,
+                index,
+                generation: 0,
+            };
+        };
+
+        let slot = &mut self.slots[index as usize];
+        match mem::replace(&mut slot.entry, Entry::Occupied(node)) {
+            Entry::Free { next_free } => self.free = next_free,
+            Entry::Occupied(_) => unreachable!("Occupied slot on the free list"),
+        }
+        NodeId {
+            list: self.id,
+            index,
+            generation: slot.generation,
+        }
+    }
+
+    fn release(&mut self, id: NodeId) -> ArenaNode<T> {
+        let slot = &mut self.slots[id.index as usize];
+        let entry = mem::replace(
+            &mut slot.entry,
+            Entry::Free {
+                next_free: self.free,
+            },
+        );
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free = Some(id.index);
+
+        match entry {
+            Entry::Occupied(node) => node,
+            Entry::Free { .. } => unreachable!("Freed a free slot"),
+        }
+    }
+}
+
+// The range, search, rotation, set and random operations of
+// `DoublyLinkedList`. Anything that only relinks nodes keeps their `NodeId`s
+// valid; nodes can't move from one arena to another, though, so `drain`,
+// `remove_range` and the set operations move the values into a new list.
+impl<T> ArenaList<T> {
+    pub fn rposition<P>(&self, predicate: P) -> Option<u32>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().rposition(predicate).map(|index| index as u32)
+    }
+
+    pub fn find_map<B, F>(&self, f: F) -> Option<B>
+    where
+        F: FnMut(&T) -> Option<B>,
+    {
+        self.iter().find_map(f)
+    }
+
+    pub fn remove_first(&mut self, value: &T) -> Option<T>
+    where
+        T: PartialEq,
+    {
+        let mut cursor = self.head;
+        while let Some(id) = cursor {
+            let node = self.linked(id);
+            if node.value == *value {
+                return Some(self.unlink(id));
+            }
+            cursor = node.next;
+        }
+        None
+    }
+
+    // Returns how many elements were removed.
+    pub fn remove_all(&mut self, value: &T) -> u32
+    where
+        T: PartialEq,
+    {
+        let mut cursor = self.head;
+        let mut removed = 0;
+
+        while let Some(id) = cursor {
+            let node = self.linked(id);
+            cursor = node.next;
+            if node.value == *value {
+                self.unlink(id);
+                removed += 1;
+            }
+        }
+        removed
+    }
+
+    // Unlike `insert_to`, `index` may equal the length to insert at the end.
+    pub fn insert_many<I>(&mut self, index: u32, values: I) -> Result<(), ListError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.splice(index..index, values).map(|_| ())
+    }
+
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Result<Vec<T>, ListError>
+    where
+        R: RangeBounds<u32>,
+        I: IntoIterator<Item = T>,
+    {
+        let (start, end) = linked_list::resolve_range(range, self.count)?;
+
+        // `replace_with` is user code and may panic, so it runs before the
+        // list is touched.
+        let replacement: Vec<T> = replace_with.into_iter().collect();
+
+        let mut next = self.id_at(start)?;
+        let mut removed = Vec::with_capacity((end - start) as usize);
+        for _ in start..end {
+            let id = next.expect("List shorter than its length");
+            next = self.linked(id).next;
+            removed.push(self.unlink(id));
+        }
+
+        let mut prev = match next {
+            Some(id) => self.linked(id).prev,
+            None => self.tail,
+        };
+        for value in replacement {
+            prev = Some(self.link_between(prev, next, value));
+        }
+        Ok(removed)
+    }
+
+    pub fn drain<R>(&mut self, range: R) -> Result<ArenaDrain<T>, ListError>
+    where
+        R: RangeBounds<u32>,
+    {
+        self.remove_range(range).map(|list| ArenaDrain { list })
+    }
+
+    pub fn remove_range<R>(&mut self, range: R) -> Result<ArenaList<T>, ListError>
+    where
+        R: RangeBounds<u32>,
+    {
+        let (start, end) = linked_list::resolve_range(range, self.count)?;
+
+        let mut removed = ArenaList::with_capacity((end - start) as usize);
+        let mut next = self.id_at(start)?;
+        for _ in start..end {
+            let id = next.expect("List shorter than its length");
+            next = self.linked(id).next;
+            removed.append(self.unlink(id));
+        }
+        Ok(removed)
+    }
+
+    // Moves the first `k` elements to the back, only by relinking the ends.
+    pub fn rotate_left(&mut self, k: u32) {
+        if self.count == 0 {
+            return;
+        }
+
+        let k = k % self.count;
+        if k == 0 {
+            return;
+        }
+
+        let (Some(head), Some(tail)) = (self.head, self.tail) else {
+            unreachable!("Non-empty list without both ends");
+        };
+        let new_head = self
+            .node_at(k)
+            .expect("Rotation index is always below the length");
+        let new_tail = self.linked(new_head).prev;
+
+        self.linked_mut(tail).next = Some(head);
+        self.linked_mut(head).prev = Some(tail);
+        self.linked_mut(new_head).prev = None;
+        if let Some(new_tail) = new_tail {
+            self.linked_mut(new_tail).next = None;
+        }
+        self.head = Some(new_head);
+        self.tail = new_tail;
+    }
+
+    pub fn rotate_right(&mut self, k: u32) {
+        if self.count == 0 {
+            return;
+        }
+
+        self.rotate_left(self.count - k % self.count);
+    }
+
+    // Fisher-Yates over the node ids; values stay in their slots, so every
+    // `NodeId` still refers to the same value afterwards.
+    pub fn shuffle(&mut self, rng: &mut XorShift) {
+        let mut ids = Vec::with_capacity(self.count as usize);
+        let mut cursor = self.head;
+        while let Some(id) = cursor {
+            ids.push(id);
+            cursor = self.linked(id).next;
+        }
+
+        for i in (1..ids.len()).rev() {
+            let j = rng.below(i as u64 + 1) as usize;
+            ids.swap(i, j);
+        }
+
+        for (position, &id) in ids.iter().enumerate() {
+            let node = self.linked_mut(id);
+            node.prev = position.checked_sub(1).map(|before| ids[before]);
+            node.next = ids.get(position + 1).copied();
+        }
+        self.head = ids.first().copied();
+        self.tail = ids.last().copied();
+    }
+
+    pub fn choose(&self, rng: &mut XorShift) -> Option<&T> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let index = rng.below(u64::from(self.count)) as u32;
+        Some(
+            self.get(index)
+                .expect("Random index is always below the length"),
+        )
+    }
+
+    // Reservoir sampling, as in `DoublyLinkedList::sample`: `k` distinct
+    // elements (all of them if the list is shorter), in reservoir order.
+    pub fn sample(&self, k: u32, rng: &mut XorShift) -> Vec<&T> {
+        let k = k as usize;
+        let mut reservoir = Vec::with_capacity(k.min(self.count as usize));
+
+        for (seen, value) in self.iter().enumerate() {
+            if seen < k {
+                reservoir.push(value);
+                continue;
+            }
+
+            let slot = rng.below(seen as u64 + 1) as usize;
+            if slot < k {
+                reservoir[slot] = value;
+            }
+        }
+        reservoir
+    }
+
+    // The set operations expect both inputs to be sorted.
+    pub fn merge<F>(mut self, mut other: ArenaList<T>, mut cmp: F) -> ArenaList<T>
+    where
+        F: FnMut(&T, &T) -> cmp::Ordering,
+    {
+        let mut result = ArenaList::with_capacity((self.count + other.count) as usize);
+
+        while let Some(order) = compare_fronts(&self, &other, &mut cmp) {
+            let value = match order {
+                cmp::Ordering::Greater => other.pop_front(),
+                _ => self.pop_front(),
+            };
+            result.append(value.expect("Compared list has a front"));
+        }
+
+        result.extend(self);
+        result.extend(other);
+        result
+    }
+
+    pub fn union(mut self, mut other: ArenaList<T>) -> ArenaList<T>
+    where
+        T: Ord,
+    {
+        let mut result = ArenaList::new();
+
+        while let Some(order) = compare_fronts(&self, &other, T::cmp) {
+            let value = match order {
+                cmp::Ordering::Less => self.pop_front(),
+                cmp::Ordering::Greater => other.pop_front(),
+                cmp::Ordering::Equal => {
+                    other.pop_front();
+                    self.pop_front()
+                }
+            };
+            result.append(value.expect("Compared list has a front"));
+        }
+
+        result.extend(self);
+        result.extend(other);
+        result
+    }
+
+    pub fn intersection(mut self, mut other: ArenaList<T>) -> ArenaList<T>
+    where
+        T: Ord,
+    {
+        let mut result = ArenaList::new();
+
+        while let Some(order) = compare_fronts(&self, &other, T::cmp) {
+            match order {
+                cmp::Ordering::Less => {
+                    self.pop_front();
+                }
+                cmp::Ordering::Greater => {
+                    other.pop_front();
+                }
+                cmp::Ordering::Equal => {
+                    other.pop_front();
+                    let value = self.pop_front();
+                    result.append(value.expect("Compared list has a front"));
+                }
+            }
+        }
+
+        result
+    }
+
+    // Elements of `self` that are not in `other`.
+    pub fn difference(mut self, mut other: ArenaList<T>) -> ArenaList<T>
+    where
+        T: Ord,
+    {
+        let mut result = ArenaList::new();
+
+        while let Some(order) = compare_fronts(&self, &other, T::cmp) {
+            match order {
+                cmp::Ordering::Less => {
+                    let value = self.pop_front();
+                    result.append(value.expect("Compared list has a front"));
+                }
+                cmp::Ordering::Greater => {
+                    other.pop_front();
+                }
+                cmp::Ordering::Equal => {
+                    self.pop_front();
+                    other.pop_front();
+                }
+            }
+        }
+
+        result.extend(self);
+        result
+    }
+
+    // The node at `index`, or `None` for the position just past the end.
+    fn id_at(&self, index: u32) -> Result<Option<NodeId>, ListError> {
+        if index == self.count {
+            return Ok(None);
+        }
+        self.node_at(index).map(Some)
+    }
+}
+
+fn compare_fronts<T, F>(a: &ArenaList<T>, b: &ArenaList<T>, mut cmp: F) -> Option<cmp::Ordering>
+where
+    F: FnMut(&T, &T) -> cmp::Ordering,
+{
+    match (a.first(), b.first()) {
+        (Ok(a), Ok(b)) => Some(cmp(a, b)),
+        _ => None,
+    }
+}
+
+impl<T> FromIterator<T> for ArenaList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = ArenaList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for ArenaList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.append(value);
+        }
+    }
+}
+
+impl<T> IntoIterator for ArenaList<T> {
+    type Item = T;
+    type IntoIter = ArenaDrain<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ArenaDrain { list: self }
+    }
+}
+
+impl<T> Deque for ArenaList<T> {
+    type Item = T;
+
+    fn push_front(&mut self, value: T) {
+        ArenaList::push_front(self, value);
+    }
+
+    fn push_back(&mut self, value: T) {
+        self.append(value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        ArenaList::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        self.pop_last()
+    }
+
+    fn peek_front_with<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        self.first().ok().map(f)
+    }
+
+    fn peek_back_with<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        self.last().ok().map(f)
+    }
+
+    fn len(&self) -> usize {
+        self.count as usize
+    }
+}
+
+impl Subject for ArenaList<u32> {
+    fn with_capacity(_capacity: usize) -> Self {
+        ArenaList::new()
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        None
+    }
+
+    fn get(&self, index: usize) -> Option<u32> {
+        let index = u32::try_from(index).ok()?;
+        ArenaList::get(self, index).ok().copied()
+    }
+}
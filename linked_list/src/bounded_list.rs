@@ -0,0 +1,165 @@
+use std::fmt;
+
+use crate::linked_list::DoublyLinkedList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvictionPolicy {
+    // Evicts from the front, where the earliest pushed elements are.
+    DropOldest,
+    // Evicts the most recently pushed elements already in the list.
+    DropNewest,
+    // Leaves the list alone and hands the new value back.
+    Reject,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum PushError<T> {
+    // The list is full and the policy is `Reject`.
+    Rejected(T),
+    // The value weighs more than the whole budget, so it can never fit.
+    TooHeavy(T),
+}
+
+impl<T> PushError<T> {
+    pub fn into_inner(self) -> T {
+        match self {
+            PushError::Rejected(value) | PushError::TooHeavy(value) => value,
+        }
+    }
+}
+
+impl<T> fmt::Display for PushError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PushError::Rejected(_) => write!(f, "list is full"),
+            PushError::TooHeavy(_) => write!(f, "value exceeds the weight budget"),
+        }
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for PushError<T> {}
+
+pub struct BoundedList<T> {
+    list: DoublyLinkedList<T>,
+    policy: EvictionPolicy,
+    max_len: Option<u32>,
+    max_weight: Option<usize>,
+    weight: usize,
+    weigh: Box<dyn Fn(&T) -> usize>,
+    on_evict: Option<Box<dyn FnMut(T)>>,
+}
+
+impl<T> BoundedList<T> {
+    // Unbounded until `max_len` and/or `max_weight` are set.
+    pub fn new(policy: EvictionPolicy) -> Self {
+        BoundedList {
+            list: DoublyLinkedList::new(),
+            policy,
+            max_len: None,
+            max_weight: None,
+            weight: 0,
+            weigh: Box::new(|_| 0),
+            on_evict: None,
+        }
+    }
+
+    pub fn max_len(mut self, max_len: u32) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+
+    // Reweighs whatever is already in the list, since the builder can be
+    // chained after pushes.
+    pub fn max_weight<F>(mut self, max_weight: usize, weigh: F) -> Self
+    where
+        F: Fn(&T) -> usize + 'static,
+    {
+        self.max_weight = Some(max_weight);
+        self.weight = self.list.iter().fold(0, |total, node| {
+            total + weigh(node.borrow().value.as_ref().expect("Node without a value"))
+        });
+        self.weigh = Box::new(weigh);
+        self
+    }
+
+    // Evicted elements go to `on_evict` instead of being returned by `push`.
+    pub fn on_evict<F>(mut self, on_evict: F) -> Self
+    where
+        F: FnMut(T) + 'static,
+    {
+        self.on_evict = Some(Box::new(on_evict));
+        self
+    }
+
+    pub fn len(&self) -> u32 {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn weight(&self) -> usize {
+        self.weight
+    }
+
+    pub fn list(&self) -> &DoublyLinkedList<T> {
+        &self.list
+    }
+
+    pub fn into_list(self) -> DoublyLinkedList<T> {
+        self.list
+    }
+
+    // Appends `value`, evicting according to the policy until it fits. The
+    // evicted elements are returned unless an `on_evict` callback is set.
+    pub fn push(&mut self, value: T) -> Result<Vec<T>, PushError<T>> {
+        let value_weight = (self.weigh)(&value);
+        if self.max_weight.is_some_and(|max| value_weight > max) {
+            return Err(PushError::TooHeavy(value));
+        }
+        if self.max_len == Some(0) {
+            return Err(PushError::Rejected(value));
+        }
+
+        let mut evicted = Vec::new();
+        while !self.fits(value_weight) {
+            let removed = match self.policy {
+                EvictionPolicy::DropOldest => self.list.pop_at(0).ok(),
+                EvictionPolicy::DropNewest => self.list.pop_last(),
+                EvictionPolicy::Reject => return Err(PushError::Rejected(value)),
+            };
+            let removed = removed.expect("Over budget with an empty list");
+
+            self.weight -= (self.weigh)(&removed);
+            match self.on_evict.as_mut() {
+                Some(on_evict) => on_evict(removed),
+                None => evicted.push(removed),
+            }
+        }
+
+        self.weight += value_weight;
+        self.list.append(value);
+        Ok(evicted)
+    }
+
+    pub fn pop_first(&mut self) -> Option<T> {
+        let value = self.list.pop_at(0).ok()?;
+        self.weight -= (self.weigh)(&value);
+        Some(value)
+    }
+
+    pub fn pop_last(&mut self) -> Option<T> {
+        let value = self.list.pop_last()?;
+        self.weight -= (self.weigh)(&value);
+        Some(value)
+    }
+
+    fn fits(&self, value_weight: usize) -> bool {
+        let len_fits = self.max_len.is_none_or(|max| self.list.len() < max);
+        let weight_fits = self
+            .max_weight
+            .is_none_or(|max| self.weight + value_weight <= max);
+        len_fits && weight_fits
+    }
+}
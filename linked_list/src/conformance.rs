@@ -0,0 +1,305 @@
+use std::{collections::VecDeque, fmt};
+
+use crate::deque::Deque;
+use crate::linked_list::DoublyLinkedList;
+use crate::random::XorShift;
+
+// Checks a container against `VecDeque`, which serves as the reference model:
+// every operation is applied to both and their answers have to agree. Each
+// implementation runs the whole suite from one test:
+//
+//     #[test]
+//     fn conforms_to_vec_deque() {
+//         conformance::check_all::<MyList<u32>>();
+//     }
+
+// What the suite needs from a container besides the `Deque` operations.
+// Test values are small numbers, so any integer element type will do.
+pub trait Subject: Deque<Item: Copy + PartialEq + fmt::Debug + From<u16>> {
+    // Unbounded containers ignore `capacity`.
+    fn with_capacity(capacity: usize) -> Self;
+
+    // `None` for unbounded containers. The suite never pushes onto a full
+    // container, because what happens then differs between implementations.
+    fn capacity(&self) -> Option<usize>;
+
+    // Counted from the front.
+    fn get(&self, index: usize) -> Option<Self::Item>;
+}
+
+pub fn check_all<S: Subject>() {
+    check_empty::<S>();
+    check_single_element::<S>();
+    check_both_ends::<S>();
+    check_fill_and_drain::<S>();
+    check_wrap_around::<S>();
+    check_index_bounds::<S>();
+
+    for seed in 1..=8 {
+        check_random::<S>(seed, 2_000, 7);
+        check_random::<S>(seed, 2_000, 64);
+    }
+}
+
+pub fn check_empty<S: Subject>() {
+    let mut checker = Checker::<S>::new(4, "empty");
+    checker.verify();
+    checker.apply(Op::PopFront);
+    checker.apply(Op::PopBack);
+    checker.apply(Op::PushBack(1));
+    checker.apply(Op::PopFront);
+    checker.apply(Op::PopBack);
+}
+
+pub fn check_single_element<S: Subject>() {
+    let sequences = [
+        [Op::PushBack(1), Op::PopBack],
+        [Op::PushBack(2), Op::PopFront],
+        [Op::PushFront(3), Op::PopFront],
+        [Op::PushFront(4), Op::PopBack],
+    ];
+
+    let mut checker = Checker::<S>::new(4, "single element");
+    for sequence in sequences {
+        for op in sequence {
+            checker.apply(op);
+        }
+        checker.apply(Op::PopFront);
+    }
+}
+
+pub fn check_both_ends<S: Subject>() {
+    let mut checker = Checker::<S>::new(8, "both ends");
+    for value in 0..4 {
+        checker.apply(Op::PushBack(value));
+        checker.apply(Op::PushFront(value + 10));
+    }
+    for _ in 0..4 {
+        checker.apply(Op::PopFront);
+        checker.apply(Op::PopBack);
+    }
+    checker.apply(Op::PopFront);
+}
+
+pub fn check_fill_and_drain<S: Subject>() {
+    let mut checker = Checker::<S>::new(16, "fill and drain");
+    let count = checker.room().min(16) as u16;
+
+    for value in 0..count {
+        checker.apply(Op::PushBack(value));
+    }
+    for _ in 0..count {
+        checker.apply(Op::PopFront);
+    }
+    for value in 0..count {
+        checker.apply(Op::PushFront(value));
+    }
+    for _ in 0..count {
+        checker.apply(Op::PopFront);
+    }
+}
+
+// Keeps a small container close to full while its contents travel around
+// it, so ring buffers wrap their indices in both directions.
+pub fn check_wrap_around<S: Subject>() {
+    let mut checker = Checker::<S>::new(4, "wrap around");
+    let mut value = 0;
+
+    for _ in 0..10 {
+        for _ in 0..3 {
+            checker.apply(Op::PushBack(value));
+            value += 1;
+        }
+        checker.apply(Op::PopFront);
+        checker.apply(Op::PopFront);
+        checker.apply(Op::PopFront);
+    }
+    for _ in 0..10 {
+        checker.apply(Op::PushFront(value));
+        checker.apply(Op::PushFront(value + 1));
+        checker.apply(Op::PopBack);
+        checker.apply(Op::PopBack);
+        value += 2;
+    }
+}
+
+pub fn check_index_bounds<S: Subject>() {
+    let mut checker = Checker::<S>::new(4, "index bounds");
+    checker.verify_index(0);
+    checker.verify_index(usize::MAX);
+
+    for value in 0..3 {
+        checker.apply(Op::PushBack(value));
+    }
+    checker.apply(Op::PopFront);
+    checker.apply(Op::PushBack(3));
+    checker.apply(Op::PushFront(4));
+
+    for index in 0..8 {
+        checker.verify_index(index);
+    }
+    checker.verify_index(usize::MAX);
+}
+
+// A reproducible random mix of operations; a failure names the seed and
+// the step, so it can be replayed on its own.
+pub fn check_random<S: Subject>(seed: u64, steps: usize, capacity: usize) {
+    let context = format!("seed {}, capacity {}", seed, capacity);
+    let mut checker = Checker::<S>::new(capacity, &context);
+    let mut rng = XorShift::new(seed);
+
+    for step in 0..steps {
+        checker.step = step;
+        let can_push = checker.room() > 0;
+        let op = match rng.below(10) {
+            0..=2 if can_push => Op::PushBack(rng.below(1_000) as u16),
+            3..=4 if can_push => Op::PushFront(rng.below(1_000) as u16),
+            0..=5 => Op::PopFront,
+            _ => Op::PopBack,
+        };
+
+        checker.apply(op);
+        if step % 16 == 0 {
+            checker.verify_contents();
+        }
+    }
+    checker.verify_contents();
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    PushFront(u16),
+    PushBack(u16),
+    PopFront,
+    PopBack,
+}
+
+struct Checker<S: Subject> {
+    subject: S,
+    model: VecDeque<S::Item>,
+    context: String,
+    step: usize,
+}
+
+impl<S: Subject> Checker<S> {
+    fn new(capacity: usize, context: &str) -> Self {
+        Checker {
+            subject: S::with_capacity(capacity),
+            model: VecDeque::new(),
+            context: context.to_owned(),
+            step: 0,
+        }
+    }
+
+    // How many more values can be pushed; unbounded containers are treated
+    // as always having room.
+    fn room(&self) -> usize {
+        match self.subject.capacity() {
+            Some(capacity) => capacity - self.model.len(),
+            None => usize::MAX,
+        }
+    }
+
+    fn apply(&mut self, op: Op) {
+        match op {
+            Op::PushFront(value) => {
+                self.subject.push_front(value.into());
+                self.model.push_front(value.into());
+            }
+            Op::PushBack(value) => {
+                self.subject.push_back(value.into());
+                self.model.push_back(value.into());
+            }
+            Op::PopFront => {
+                let expected = self.model.pop_front();
+                assert_eq!(
+                    self.subject.pop_front(),
+                    expected,
+                    "{:?}, {}",
+                    op,
+                    self.at()
+                );
+            }
+            Op::PopBack => {
+                let expected = self.model.pop_back();
+                assert_eq!(self.subject.pop_back(), expected, "{:?}, {}", op, self.at());
+            }
+        }
+        self.verify();
+    }
+
+    // Cheap checks, run after every operation.
+    fn verify(&self) {
+        let at = self.at();
+        assert_eq!(self.subject.len(), self.model.len(), "len, {}", at);
+        assert_eq!(
+            self.subject.is_empty(),
+            self.model.is_empty(),
+            "is_empty, {}",
+            at
+        );
+        assert_eq!(
+            self.subject.peek_front(),
+            self.model.front().copied(),
+            "peek_front, {}",
+            at
+        );
+        assert_eq!(
+            self.subject.peek_back(),
+            self.model.back().copied(),
+            "peek_back, {}",
+            at
+        );
+    }
+
+    fn verify_contents(&self) {
+        for index in 0..=self.model.len() {
+            self.verify_index(index);
+        }
+    }
+
+    fn verify_index(&self, index: usize) {
+        assert_eq!(
+            self.subject.get(index),
+            self.model.get(index).copied(),
+            "get({}), {}",
+            index,
+            self.at()
+        );
+    }
+
+    fn at(&self) -> String {
+        format!("step {} of {}", self.step, self.context)
+    }
+}
+
+impl Subject for VecDeque<u32> {
+    fn with_capacity(_capacity: usize) -> Self {
+        VecDeque::new()
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        None
+    }
+
+    fn get(&self, index: usize) -> Option<u32> {
+        VecDeque::get(self, index).copied()
+    }
+}
+
+impl Subject for DoublyLinkedList<u32> {
+    fn with_capacity(_capacity: usize) -> Self {
+        DoublyLinkedList::new()
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        None
+    }
+
+    fn get(&self, index: usize) -> Option<u32> {
+        let index = u32::try_from(index).ok()?;
+        let element = DoublyLinkedList::get(self, index).ok()?;
+        let value = *element.borrow();
+        Some(value)
+    }
+}
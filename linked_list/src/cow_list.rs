@@ -0,0 +1,167 @@
+use std::rc::Rc;
+
+use crate::linked_list::{DoublyLinkedList, ElementRef, ListError};
+
+// Clones share one node chain behind an `Rc`. Reads go straight to the
+// shared list, but only through accessors that hand out values: the list's
+// own `iter` yields its nodes, and a write through one of those would show
+// up in every clone. The first write from a holder that isn't the only one
+// left copies the chain, so the other holders keep seeing the list as it was.
+pub struct CowList<T> {
+    root: Rc<DoublyLinkedList<T>>,
+}
+
+impl<T> Clone for CowList<T> {
+    // Shares the chain; nothing is copied until someone writes.
+    fn clone(&self) -> Self {
+        CowList {
+            root: Rc::clone(&self.root),
+        }
+    }
+}
+
+impl<T> Default for CowList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<DoublyLinkedList<T>> for CowList<T> {
+    fn from(list: DoublyLinkedList<T>) -> Self {
+        CowList {
+            root: Rc::new(list),
+        }
+    }
+}
+
+impl<T> CowList<T> {
+    pub fn new() -> Self {
+        DoublyLinkedList::new().into()
+    }
+
+    // How many `CowList`s currently share this chain, including `self`.
+    pub fn share_count(&self) -> usize {
+        Rc::strong_count(&self.root)
+    }
+
+    pub fn is_unique(&self) -> bool {
+        self.share_count() == 1
+    }
+
+    pub fn shares_with(&self, other: &CowList<T>) -> bool {
+        Rc::ptr_eq(&self.root, &other.root)
+    }
+
+    // The chain currently in use; it changes only when a write copies it.
+    pub fn as_ptr(&self) -> *const DoublyLinkedList<T> {
+        Rc::as_ptr(&self.root)
+    }
+
+    pub fn len(&self) -> u32 {
+        self.root.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_empty()
+    }
+
+    pub fn get(&self, index: u32) -> Result<ElementRef<'_, T>, ListError> {
+        self.root.get(index)
+    }
+
+    pub fn first(&self) -> Result<ElementRef<'_, T>, ListError> {
+        self.root.first()
+    }
+
+    pub fn last(&self) -> Result<ElementRef<'_, T>, ListError> {
+        self.root.last()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ElementRef<'_, T>> {
+        self.root.iter().map(ElementRef::new)
+    }
+
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.root.contains(value)
+    }
+
+    pub fn position<P>(&self, predicate: P) -> Option<u32>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.root.position(predicate)
+    }
+
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.root.to_vec()
+    }
+}
+
+impl<T: Clone> CowList<T> {
+    // Copies the chain first if anyone else shares it.
+    pub fn to_mut(&mut self) -> &mut DoublyLinkedList<T> {
+        if !self.is_unique() {
+            self.root = Rc::new(DoublyLinkedList::clone(&self.root));
+        }
+        Rc::get_mut(&mut self.root).expect("Unshared root")
+    }
+
+    pub fn into_list(self) -> DoublyLinkedList<T> {
+        Rc::try_unwrap(self.root).unwrap_or_else(|shared| DoublyLinkedList::clone(&shared))
+    }
+
+    pub fn append(&mut self, value: T) {
+        self.to_mut().append(value);
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        self.to_mut().push_front(value);
+    }
+
+    // Operations that would fail, or pop from an empty list, are caught
+    // before `to_mut` so they never trigger a copy.
+    pub fn pop_last(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.to_mut().pop_last()
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.to_mut().pop_front()
+    }
+
+    pub fn insert_to(&mut self, index: u32, value: T) -> Result<(), ListError> {
+        self.check_index(index)?;
+        self.to_mut().insert_to(index, value)
+    }
+
+    pub fn pop_at(&mut self, index: u32) -> Result<T, ListError> {
+        self.check_index(index)?;
+        self.to_mut().pop_at(index)
+    }
+
+    pub fn set(&mut self, index: u32, value: T) -> Result<T, ListError> {
+        self.check_index(index)?;
+        self.to_mut().set(index, value)
+    }
+
+    fn check_index(&self, index: u32) -> Result<(), ListError> {
+        if index >= self.len() {
+            return Err(ListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::VecDeque;
+
+// The operations a list needs to back a `Stack` or a `Queue`.
+pub trait Deque {
+    type Item;
+
+    fn push_front(&mut self, value: Self::Item);
+
+    fn push_back(&mut self, value: Self::Item);
+
+    fn pop_front(&mut self) -> Option<Self::Item>;
+
+    fn pop_back(&mut self) -> Option<Self::Item>;
+
+    // The `Rc` lists keep values behind a `RefCell`, so a plain reference
+    // can't be handed out; the value is lent to `f` instead.
+    fn peek_front_with<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&Self::Item) -> R;
+
+    fn peek_back_with<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&Self::Item) -> R;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn peek_front(&self) -> Option<Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        self.peek_front_with(Clone::clone)
+    }
+
+    fn peek_back(&self) -> Option<Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        self.peek_back_with(Clone::clone)
+    }
+}
+
+impl<T> Deque for VecDeque<T> {
+    type Item = T;
+
+    fn push_front(&mut self, value: T) {
+        VecDeque::push_front(self, value);
+    }
+
+    fn push_back(&mut self, value: T) {
+        VecDeque::push_back(self, value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        VecDeque::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        VecDeque::pop_back(self)
+    }
+
+    fn peek_front_with<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        self.front().map(f)
+    }
+
+    fn peek_back_with<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        self.back().map(f)
+    }
+
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+}
+
+// Last in, first out: everything happens at the back of `L`.
+#[derive(Default)]
+pub struct Stack<L> {
+    list: L,
+}
+
+impl<L: Deque> Stack<L> {
+    pub fn new() -> Self
+    where
+        L: Default,
+    {
+        Stack { list: L::default() }
+    }
+
+    pub fn push(&mut self, value: L::Item) {
+        self.list.push_back(value);
+    }
+
+    pub fn pop(&mut self) -> Option<L::Item> {
+        self.list.pop_back()
+    }
+
+    pub fn peek(&self) -> Option<L::Item>
+    where
+        L::Item: Clone,
+    {
+        self.list.peek_back()
+    }
+
+    pub fn peek_with<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&L::Item) -> R,
+    {
+        self.list.peek_back_with(f)
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn into_inner(self) -> L {
+        self.list
+    }
+}
+
+// First in, first out: values go in at the back and come out at the front.
+#[derive(Default)]
+pub struct Queue<L> {
+    list: L,
+}
+
+impl<L: Deque> Queue<L> {
+    pub fn new() -> Self
+    where
+        L: Default,
+    {
+        Queue { list: L::default() }
+    }
+
+    pub fn push(&mut self, value: L::Item) {
+        self.list.push_back(value);
+    }
+
+    pub fn pop(&mut self) -> Option<L::Item> {
+        self.list.pop_front()
+    }
+
+    pub fn peek(&self) -> Option<L::Item>
+    where
+        L::Item: Clone,
+    {
+        self.list.peek_front()
+    }
+
+    pub fn peek_with<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&L::Item) -> R,
+    {
+        self.list.peek_front_with(f)
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn into_inner(self) -> L {
+        self.list
+    }
+}
+
+impl<L: Deque> From<L> for Stack<L> {
+    fn from(list: L) -> Self {
+        Stack { list }
+    }
+}
+
+impl<L: Deque> From<L> for Queue<L> {
+    fn from(list: L) -> Self {
+        Queue { list }
+    }
+}
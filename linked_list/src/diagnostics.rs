@@ -0,0 +1,145 @@
+use std::{
+    cell::RefCell,
+    fmt,
+    rc::{Rc, Weak},
+};
+
+// Reference counts of one node, taken without the temporary handle the
+// report itself holds while walking the list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeCounts {
+    pub index: u32,
+    pub strong: usize,
+    pub weak: usize,
+    pub issues: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostics {
+    pub name: &'static str,
+    pub nodes: Vec<NodeCounts>,
+}
+
+impl Diagnostics {
+    pub fn is_healthy(&self) -> bool {
+        self.nodes.iter().all(|node| node.issues.is_empty())
+    }
+
+    pub fn flagged(&self) -> impl Iterator<Item = &NodeCounts> {
+        self.nodes.iter().filter(|node| !node.issues.is_empty())
+    }
+}
+
+impl fmt::Display for Diagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let flagged = self.flagged().count();
+        writeln!(
+            f,
+            "{}: {} nodes, {} flagged",
+            self.name,
+            self.nodes.len(),
+            flagged
+        )?;
+        for node in &self.nodes {
+            let marker = if node.issues.is_empty() { ' ' } else { '!' };
+            write!(
+                f,
+                "{} node {:>5}: strong {:>2}, weak {:>2}",
+                marker, node.index, node.strong, node.weak
+            )?;
+            for issue in &node.issues {
+                write!(f, "  [{}]", issue)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+// Compares the counts of a node against what the list layout expects.
+pub fn check_counts<N>(
+    index: u32,
+    node: &Rc<RefCell<N>>,
+    expected_strong: usize,
+    expected_weak: usize,
+) -> NodeCounts {
+    // The caller holds one extra strong handle to `node` while checking it.
+    let strong = Rc::strong_count(node) - 1;
+    let weak = Rc::weak_count(node);
+
+    let mut issues = Vec::new();
+    if strong > expected_strong {
+        issues.push(format!(
+            "{} extra strong reference(s): leaked handle or cycle",
+            strong - expected_strong
+        ));
+    }
+    if weak != expected_weak {
+        issues.push(format!("expected {} weak reference(s)", expected_weak));
+    }
+
+    NodeCounts {
+        index,
+        strong,
+        weak,
+        issues,
+    }
+}
+
+pub trait Diagnose {
+    type Node;
+
+    fn diagnostics(&self) -> Diagnostics;
+
+    // Keeps a weak handle to every node, so it can check later that they
+    // were all freed.
+    fn watch_nodes(&self) -> DropWatch<Self::Node>;
+}
+
+pub struct DropWatch<N> {
+    nodes: Vec<Weak<RefCell<N>>>,
+}
+
+impl<N> DropWatch<N> {
+    pub fn new(nodes: Vec<Weak<RefCell<N>>>) -> Self {
+        DropWatch { nodes }
+    }
+
+    // Positions (at watch time) of nodes that are still allocated.
+    pub fn alive(&self) -> Vec<usize> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| node.strong_count() > 0)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    pub fn assert_all_freed(&self) {
+        let alive = self.alive();
+        assert!(
+            alive.is_empty(),
+            "{} of {} nodes still allocated, at positions {:?}",
+            alive.len(),
+            self.nodes.len(),
+            alive
+        );
+    }
+}
+
+// Drops `list` and panics if any of its nodes outlived it.
+pub fn assert_drop_frees_all<L: Diagnose>(list: L) {
+    let watch = list.watch_nodes();
+    drop(list);
+    watch.assert_all_freed();
+}
+
+// The check for lists whose nodes aren't `Rc`s and so can't be watched:
+// builds an `L` of `len` handles to one shared value, drops it and returns
+// how many of those handles are still alive.
+pub fn leaked_values<L: FromIterator<Rc<()>>>(len: usize) -> usize {
+    let value = Rc::new(());
+    let list: L = (0..len).map(|_| Rc::clone(&value)).collect();
+    drop(list);
+    Rc::strong_count(&value) - 1
+}
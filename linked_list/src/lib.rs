@@ -1 +1,12 @@
+pub mod arena_list;
+pub mod bounded_list;
+pub mod conformance;
+pub mod cow_list;
+pub mod deque;
+pub mod diagnostics;
 pub mod linked_list;
+pub mod memory;
+pub mod random;
+pub mod sentinel_list;
+pub mod text_buffer;
+pub mod unrolled_list;
@@ -1,169 +1,307 @@
+mod codec;
+mod convert;
+mod deque;
+mod diagnostics;
+mod drain;
+mod element;
+mod error;
+mod family;
 mod iter;
+mod memory;
+mod merge;
 mod node;
+mod rotate;
+mod search;
+mod self_organizing;
+mod shuffle;
+mod splice;
+mod view;
 
-use std::{
-    cell::RefCell,
-    rc::{Rc, Weak},
-};
+use std::ops::{Bound, RangeBounds};
 
+pub use self::codec::{CodecError, ListCodec};
+pub use self::convert::IntoIter;
+pub use self::drain::Drain;
+pub use self::element::{ElementMut, ElementRef};
+pub use self::error::ListError;
+pub use self::family::{ArcMutexFamily, ArcRwLockFamily, PointerFamily, RcFamily};
 use self::iter::Iter;
 use self::node::Node;
+pub use self::self_organizing::{Found, PromotionPolicy, SelfOrganizingList};
+pub use self::view::{ListView, ViewIter};
 
-pub struct DoublyLinkedList<T> {
+// `F` picks how nodes are shared; see `PointerFamily`. The core operations
+// work with every family, the rest of the API is specific to `RcFamily`.
+pub struct DoublyLinkedList<T, F: PointerFamily = RcFamily> {
     count: u32,
-    head: Option<Rc<RefCell<Node<T>>>>,
-    tail: Option<Weak<RefCell<Node<T>>>>,
+    head: Option<F::Pointer<Node<T, F>>>,
+    tail: Option<F::WeakPointer<Node<T, F>>>,
 }
 
-impl<T> DoublyLinkedList<T> {
-    pub fn new() -> Self {
+pub type SyncDoublyLinkedList<T> = DoublyLinkedList<T, ArcMutexFamily>;
+
+impl<T, F: PointerFamily> Default for DoublyLinkedList<T, F> {
+    fn default() -> Self {
         DoublyLinkedList {
             count: 0,
             head: None,
             tail: None,
         }
     }
+}
+
+impl<T> DoublyLinkedList<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
 
     pub fn iter(&self) -> Iter<T> {
         Iter {
             current: self.head.clone(),
         }
     }
-}
 
-impl<T: std::fmt::Display> DoublyLinkedList<T> {
-    pub fn append(&mut self, value: T) {
-        let new_node = Node::new(value);
-
-        match self.tail.take() {
-            Some(old_tail_weak) => {
-                if let Some(old_tail) = old_tail_weak.upgrade() {
-                    old_tail.borrow_mut().next = Some(Rc::clone(&new_node));
-                    new_node.borrow_mut().prev = Some(old_tail_weak);
-                }
-            }
-            None => {
-                self.head = Some(Rc::clone(&new_node));
-            }
+    pub fn get(&self, index: u32) -> Result<ElementRef<'_, T>, ListError> {
+        self.node_at(index).map(ElementRef::new)
+    }
+
+    pub fn get_mut(&mut self, index: u32) -> Result<ElementMut<'_, T>, ListError> {
+        self.node_at(index).map(ElementMut::new)
+    }
+
+    pub fn first(&self) -> Result<ElementRef<'_, T>, ListError> {
+        self.get(0)
+    }
+
+    pub fn last(&self) -> Result<ElementRef<'_, T>, ListError> {
+        self.get(self.count.saturating_sub(1))
+    }
+
+    pub fn set(&mut self, index: u32, value: T) -> Result<T, ListError> {
+        let node = self.node_at(index)?;
+        let old = node.borrow_mut().value.replace(value);
+        Ok(old.expect("Node without a value"))
+    }
+
+    pub fn swap(&mut self, a: u32, b: u32) -> Result<(), ListError> {
+        let node_a = self.node_at(a)?;
+        let node_b = self.node_at(b)?;
+
+        if a != b {
+            std::mem::swap(
+                &mut node_a.borrow_mut().value,
+                &mut node_b.borrow_mut().value,
+            );
         }
-        self.tail = Some(Rc::downgrade(&new_node));
-        self.count += 1;
+        Ok(())
     }
+}
 
-    pub fn pop_last(&mut self) -> Option<T> {
-        self.tail.take().and_then(|old_tail_weak| {
-            old_tail_weak.upgrade().map(|old_tail| {
-                if let Some(prev_node_weak) = old_tail.borrow_mut().prev.take() {
-                    if let Some(prev_node) = prev_node_weak.upgrade() {
-                        prev_node.borrow_mut().next = None;
-                        self.tail = Some(prev_node_weak);
-                        self.count -= 1;
-                    }
-                } else {
-                    self.head = None;
-                    self.count = 0;
-                }
-
-                Rc::try_unwrap(old_tail)
-                    .ok()
-                    .expect("Something went wrong")
-                    .into_inner()
-                    .value
-                    .unwrap()
-            })
-        })
-    }
-
-    pub fn insert_to(&mut self, index: u32, value: T) -> Result<(), String> {
+impl<T, F: PointerFamily> DoublyLinkedList<T, F> {
+    pub fn len(&self) -> u32 {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    // Walks from whichever end is closer to `index`.
+    fn node_at(&self, index: u32) -> Result<F::Pointer<Node<T, F>>, ListError> {
         if index >= self.count {
-            return Err("Invalid index!".to_owned());
+            return Err(ListError::IndexOutOfBounds {
+                index,
+                len: self.count,
+            });
         }
-        if index == 0 {
-            match self.head.take() {
-                Some(old_head) => {
-                    let new_node = Node::new(value);
-
-                    old_head.borrow_mut().prev = Some(Rc::downgrade(&new_node));
-                    new_node.borrow_mut().next = Some(old_head);
-
-                    self.head = Some(new_node);
-                }
-                None => {
-                    self.append(value);
-                }
-            }
-            Ok(())
-        } else {
-            let mut cursor = self.head.clone();
 
-            for _ in 0..index - 1 {
-                cursor = cursor.ok_or("Index out of bound!")?.borrow().next.clone();
+        let mut cursor;
+        if index <= self.count / 2 {
+            cursor = self.head.clone();
+            for _ in 0..index {
+                cursor = cursor.and_then(|node| F::borrow(&node).next.clone());
             }
-
-            match cursor {
-                Some(current_node) => {
-                    let new_node = Node::new(value);
-
-                    match current_node.borrow_mut().next.take() {
-                        Some(new_next_node) => {
-                            new_node.borrow_mut().next = Some(Rc::clone(&new_next_node));
-                            new_next_node.borrow_mut().prev = Some(Rc::downgrade(&new_node));
-                        }
-                        _ => {}
-                    }
-
-                    current_node.borrow_mut().next = Some(new_node);
-                    self.count += 1;
-                }
-                None => {
-                    self.append(value);
-                }
+        } else {
+            cursor = self.tail.as_ref().and_then(F::upgrade);
+            for _ in index + 1..self.count {
+                cursor =
+                    cursor.and_then(|node| F::borrow(&node).prev.as_ref().and_then(F::upgrade));
             }
-            Ok(())
         }
+
+        Ok(cursor.expect("List shorter than its count"))
     }
 
-    pub fn pop_at(&mut self, index: u32) -> Result<T, String> {
-        if index >= self.count {
-            return Err("Invalid index!".to_owned());
-        }
+    fn resolve_range<R: RangeBounds<u32>>(&self, range: R) -> Result<(u32, u32), ListError> {
+        resolve_range(range, self.count)
+    }
 
-        if index == self.count - 1 {
-            return self.pop_last().ok_or("List is empty!".to_owned());
+    // Makes `after` follow `before`, treating `None` as the list boundary.
+    fn link(
+        &mut self,
+        before: Option<&F::Pointer<Node<T, F>>>,
+        after: Option<F::Pointer<Node<T, F>>>,
+    ) {
+        match &after {
+            Some(after_node) => F::borrow_mut(after_node).prev = before.map(F::downgrade),
+            None => self.tail = before.map(F::downgrade),
         }
+        match before {
+            Some(before_node) => F::borrow_mut(before_node).next = after,
+            None => self.head = after,
+        }
+    }
 
-        let mut cursor = self.head.clone();
+    fn unlink(&mut self, node: F::Pointer<Node<T, F>>) -> T {
+        self.detach(&node);
 
-        for _ in 0..=index - 1 {
-            cursor = cursor.ok_or("Index out of bound!")?.borrow().next.clone();
-        }
+        let value = F::borrow_mut(&node).value.take();
+        value.expect("Node without a value")
+    }
 
-        let current_rc = cursor.ok_or("Unexpected error: Missing current node!")?;
-        let mut current_node = current_rc.borrow_mut();
+    // Takes `node` out of the chain but leaves its value in place, so the
+    // node itself can be linked into another list.
+    fn detach(&mut self, node: &F::Pointer<Node<T, F>>) {
+        let (prev, next) = {
+            let mut current_node = F::borrow_mut(node);
+            let prev = current_node
+                .prev
+                .take()
+                .and_then(|prev_weak| F::upgrade(&prev_weak));
+            (prev, current_node.next.take())
+        };
 
-        let prev = current_node
+        self.link(prev.as_ref(), next);
+        self.count -= 1;
+    }
+
+    fn detach_front(&mut self) -> Option<F::Pointer<Node<T, F>>> {
+        let head = self.head.clone()?;
+        self.detach(&head);
+        Some(head)
+    }
+
+    fn attach_front(&mut self, node: F::Pointer<Node<T, F>>) {
+        let head = self.head.take();
+
+        self.link(Some(&node), head);
+        self.link(None, Some(node));
+        self.count += 1;
+    }
+
+    // Links a detached `node` in right before `next`, which is in the list.
+    fn attach_before(&mut self, node: F::Pointer<Node<T, F>>, next: &F::Pointer<Node<T, F>>) {
+        let prev = F::borrow(next).prev.as_ref().and_then(F::upgrade);
+
+        self.link(prev.as_ref(), Some(node.clone()));
+        self.link(Some(&node), Some(next.clone()));
+        self.count += 1;
+    }
+
+    fn attach_back(&mut self, node: F::Pointer<Node<T, F>>) {
+        let tail = self.tail.as_ref().and_then(F::upgrade);
+
+        self.link(tail.as_ref(), Some(node.clone()));
+        self.link(Some(&node), None);
+        self.count += 1;
+    }
+
+    // Moves every node of `other` to the back of `self` in O(1).
+    fn attach_list(&mut self, mut other: DoublyLinkedList<T, F>) {
+        let Some(other_head) = other.head.take() else {
+            return;
+        };
+        let tail = self.tail.as_ref().and_then(F::upgrade);
+
+        self.link(tail.as_ref(), Some(other_head));
+        self.tail = other.tail.take();
+        self.count += other.count;
+        other.count = 0;
+    }
+}
+
+// Resolves `range` against a list of `len` elements into a half-open
+// `start..end`. Shared with the other lists that take index ranges.
+pub(crate) fn resolve_range<R: RangeBounds<u32>>(
+    range: R,
+    len: u32,
+) -> Result<(u32, u32), ListError> {
+    let invalid = |start, end| ListError::InvalidRange { start, end, len };
+
+    // A bound one past `u32::MAX` is always past the end of the list; it
+    // is reported as `u32::MAX`, the closest value the error can hold.
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1).ok_or(invalid(u32::MAX, u32::MAX))?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1).ok_or(invalid(start, u32::MAX))?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    if start > end || end > len {
+        return Err(invalid(start, end));
+    }
+    Ok((start, end))
+}
+
+impl<T, F: PointerFamily> Drop for DoublyLinkedList<T, F> {
+    // Unlinks one node at a time; letting the pointer chain drop on its own
+    // recurses once per node and overflows the stack on long lists.
+    fn drop(&mut self) {
+        while self.detach_front().is_some() {}
+    }
+}
+
+impl<T, F: PointerFamily> DoublyLinkedList<T, F> {
+    pub fn append(&mut self, value: T) {
+        self.attach_back(Node::<T, F>::new_in(value));
+    }
+
+    pub fn pop_last(&mut self) -> Option<T> {
+        let old_tail = self.tail.as_ref().and_then(F::upgrade)?;
+        Some(self.unlink(old_tail))
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        self.attach_front(Node::<T, F>::new_in(value));
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let old_head = self.head.clone()?;
+        Some(self.unlink(old_head))
+    }
+
+    pub fn insert_to(&mut self, index: u32, value: T) -> Result<(), ListError> {
+        let next_node = self.node_at(index)?;
+        let new_node = Node::<T, F>::new_in(value);
+
+        let prev = F::borrow_mut(&next_node)
             .prev
             .take()
-            .and_then(|prev_weak| prev_weak.upgrade());
-        let next = current_node.next.take();
+            .and_then(|prev_weak| F::upgrade(&prev_weak));
 
-        if let Some(prev_node) = prev.clone() {
-            prev_node.borrow_mut().next = next.clone();
-        } else {
-            self.head = next.clone();
-        }
+        F::borrow_mut(&new_node).next = Some(next_node.clone());
+        F::borrow_mut(&next_node).prev = Some(F::downgrade(&new_node));
 
-        if let Some(next_node) = next {
-            next_node.borrow_mut().prev = prev.map(|node| Rc::downgrade(&node));
-        } else {
-            self.tail = prev.map(|node| Rc::downgrade(&node));
+        match prev {
+            Some(prev_node) => {
+                F::borrow_mut(&new_node).prev = Some(F::downgrade(&prev_node));
+                F::borrow_mut(&prev_node).next = Some(new_node);
+            }
+            None => {
+                self.head = Some(new_node);
+            }
         }
 
-        self.count -= 1;
+        self.count += 1;
+        Ok(())
+    }
 
-        current_node
-            .value
-            .take()
-            .ok_or("Node without a value".to_owned())
+    pub fn pop_at(&mut self, index: u32) -> Result<T, ListError> {
+        let node = self.node_at(index)?;
+        Ok(self.unlink(node))
     }
 }
@@ -0,0 +1,367 @@
+use std::{
+    fmt,
+    io::{self, Read, Write},
+};
+
+use super::DoublyLinkedList;
+
+// Binary layout, all integers little-endian:
+//
+//   magic "DLL" | version: u8 | count: u32 | count encoded elements
+//
+// Variable-length elements (strings, byte vectors) are prefixed with their
+// length as a u32.
+const MAGIC: &[u8; 3] = b"DLL";
+const VERSION: u8 = 1;
+
+#[derive(Debug)]
+pub enum CodecError {
+    Io(io::Error),
+    InvalidHeader,
+    UnsupportedVersion(u8),
+    InvalidUtf8,
+    Parse(String),
+}
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CodecError::Io(error) => write!(f, "{}", error),
+            CodecError::InvalidHeader => write!(f, "input is not a serialized list"),
+            CodecError::UnsupportedVersion(version) => {
+                write!(f, "unsupported format version {}", version)
+            }
+            CodecError::InvalidUtf8 => write!(f, "string element is not valid UTF-8"),
+            CodecError::Parse(message) => write!(f, "parse error: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for CodecError {}
+
+impl From<io::Error> for CodecError {
+    fn from(error: io::Error) -> Self {
+        CodecError::Io(error)
+    }
+}
+
+pub trait ListCodec: Sized {
+    fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()>;
+
+    fn decode<R: Read>(reader: &mut R) -> Result<Self, CodecError>;
+
+    fn write_text(&self, out: &mut String);
+
+    // Parses one element from the front of `input` and advances past it.
+    fn parse_text(input: &mut &str) -> Result<Self, CodecError>;
+}
+
+macro_rules! impl_int_codec {
+    ($($int:ty),*) => {$(
+        impl ListCodec for $int {
+            fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                writer.write_all(&self.to_le_bytes())
+            }
+
+            fn decode<R: Read>(reader: &mut R) -> Result<Self, CodecError> {
+                let mut bytes = [0; std::mem::size_of::<$int>()];
+                reader.read_exact(&mut bytes)?;
+                Ok(<$int>::from_le_bytes(bytes))
+            }
+
+            fn write_text(&self, out: &mut String) {
+                out.push_str(&self.to_string());
+            }
+
+            fn parse_text(input: &mut &str) -> Result<Self, CodecError> {
+                let is_number = |(i, c): (usize, char)| {
+                    c.is_ascii_digit() || (i == 0 && (c == '-' || c == '+'))
+                };
+                let end = input
+                    .char_indices()
+                    .find(|&item| !is_number(item))
+                    .map_or(input.len(), |(i, _)| i);
+                let (number, rest) = input.split_at(end);
+
+                let value = number.parse().map_err(|_| {
+                    let expected = stringify!($int);
+                    CodecError::Parse(format!("expected {}, found {:?}", expected, number))
+                })?;
+                *input = rest;
+                Ok(value)
+            }
+        }
+    )*};
+}
+
+impl_int_codec!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+// `usize`/`isize` are stored as 64 bits so the format doesn't depend on the
+// platform that wrote it.
+impl ListCodec for usize {
+    fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        (*self as u64).encode(writer)
+    }
+
+    fn decode<R: Read>(reader: &mut R) -> Result<Self, CodecError> {
+        usize::try_from(u64::decode(reader)?)
+            .map_err(|_| CodecError::Parse("usize element out of range".to_owned()))
+    }
+
+    fn write_text(&self, out: &mut String) {
+        (*self as u64).write_text(out)
+    }
+
+    fn parse_text(input: &mut &str) -> Result<Self, CodecError> {
+        usize::try_from(u64::parse_text(input)?)
+            .map_err(|_| CodecError::Parse("usize element out of range".to_owned()))
+    }
+}
+
+impl ListCodec for isize {
+    fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        (*self as i64).encode(writer)
+    }
+
+    fn decode<R: Read>(reader: &mut R) -> Result<Self, CodecError> {
+        isize::try_from(i64::decode(reader)?)
+            .map_err(|_| CodecError::Parse("isize element out of range".to_owned()))
+    }
+
+    fn write_text(&self, out: &mut String) {
+        (*self as i64).write_text(out)
+    }
+
+    fn parse_text(input: &mut &str) -> Result<Self, CodecError> {
+        isize::try_from(i64::parse_text(input)?)
+            .map_err(|_| CodecError::Parse("isize element out of range".to_owned()))
+    }
+}
+
+impl ListCodec for String {
+    fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_bytes(writer, self.as_bytes())
+    }
+
+    fn decode<R: Read>(reader: &mut R) -> Result<Self, CodecError> {
+        String::from_utf8(read_bytes(reader)?).map_err(|_| CodecError::InvalidUtf8)
+    }
+
+    fn write_text(&self, out: &mut String) {
+        out.push('"');
+        for c in self.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+    }
+
+    fn parse_text(input: &mut &str) -> Result<Self, CodecError> {
+        expect(input, '"')?;
+
+        let mut value = String::new();
+        let mut chars = input.char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    *input = &input[i + 1..];
+                    return Ok(value);
+                }
+                '\\' => match chars.next() {
+                    Some((_, '"')) => value.push('"'),
+                    Some((_, '\\')) => value.push('\\'),
+                    Some((_, 'n')) => value.push('\n'),
+                    Some((_, 'r')) => value.push('\r'),
+                    Some((_, 't')) => value.push('\t'),
+                    other => {
+                        return Err(CodecError::Parse(format!(
+                            "invalid escape {:?}",
+                            other.map(|(_, c)| c)
+                        )))
+                    }
+                },
+                c => value.push(c),
+            }
+        }
+        Err(CodecError::Parse("unterminated string".to_owned()))
+    }
+}
+
+impl ListCodec for Vec<u8> {
+    fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_bytes(writer, self)
+    }
+
+    fn decode<R: Read>(reader: &mut R) -> Result<Self, CodecError> {
+        read_bytes(reader)
+    }
+
+    fn write_text(&self, out: &mut String) {
+        write_seq(out, self.iter());
+    }
+
+    fn parse_text(input: &mut &str) -> Result<Self, CodecError> {
+        let mut bytes = Vec::new();
+        parse_seq(input, |input| {
+            bytes.push(u8::parse_text(input)?);
+            Ok(())
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl<T: ListCodec> DoublyLinkedList<T> {
+    pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[VERSION])?;
+        self.count.encode(&mut writer)?;
+
+        for node in self.iter() {
+            node.borrow()
+                .value
+                .as_ref()
+                .expect("Node without a value")
+                .encode(&mut writer)?;
+        }
+        writer.flush()
+    }
+
+    pub fn read_from<R: Read>(mut reader: R) -> Result<Self, CodecError> {
+        let mut header = [0; 4];
+        reader.read_exact(&mut header)?;
+        if &header[..3] != MAGIC {
+            return Err(CodecError::InvalidHeader);
+        }
+        if header[3] != VERSION {
+            return Err(CodecError::UnsupportedVersion(header[3]));
+        }
+
+        let count = u32::decode(&mut reader)?;
+        let mut list = DoublyLinkedList::new();
+        for _ in 0..count {
+            list.append(T::decode(&mut reader)?);
+        }
+        Ok(list)
+    }
+
+    // Formats the list as `[1, 2, 3]`.
+    pub fn to_text(&self) -> String {
+        let mut out = String::from("[");
+        for (i, node) in self.iter().enumerate() {
+            if i > 0 {
+                out.push_str(", ");
+            }
+            node.borrow()
+                .value
+                .as_ref()
+                .expect("Node without a value")
+                .write_text(&mut out);
+        }
+        out.push(']');
+        out
+    }
+
+    pub fn from_text(text: &str) -> Result<Self, CodecError> {
+        let mut input = text.trim_start();
+        let mut list = DoublyLinkedList::new();
+
+        parse_seq(&mut input, |input| {
+            list.append(T::parse_text(input)?);
+            Ok(())
+        })?;
+
+        if !input.trim().is_empty() {
+            return Err(CodecError::Parse(format!(
+                "unexpected trailing input {:?}",
+                input.trim()
+            )));
+        }
+        Ok(list)
+    }
+}
+
+fn write_bytes<W: Write>(writer: &mut W, bytes: &[u8]) -> io::Result<()> {
+    let len = u32::try_from(bytes.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "element too long"))?;
+    len.encode(writer)?;
+    writer.write_all(bytes)
+}
+
+fn read_bytes<R: Read>(reader: &mut R) -> Result<Vec<u8>, CodecError> {
+    let len = u32::decode(reader)?;
+
+    // Read through `take` rather than allocating `len` bytes up front, so a
+    // corrupt length fails with EOF instead of a huge allocation.
+    let mut bytes = Vec::new();
+    reader.take(len as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != len as usize {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+    }
+    Ok(bytes)
+}
+
+fn write_seq<'a, T, I>(out: &mut String, items: I)
+where
+    T: ListCodec + 'a,
+    I: Iterator<Item = &'a T>,
+{
+    out.push('[');
+    for (i, item) in items.enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        item.write_text(out);
+    }
+    out.push(']');
+}
+
+// Parses `[item, item, ...]`, calling `parse_item` with the input positioned
+// at each item.
+fn parse_seq<F>(input: &mut &str, mut parse_item: F) -> Result<(), CodecError>
+where
+    F: FnMut(&mut &str) -> Result<(), CodecError>,
+{
+    expect(input, '[')?;
+    *input = input.trim_start();
+    if let Some(rest) = input.strip_prefix(']') {
+        *input = rest;
+        return Ok(());
+    }
+
+    loop {
+        parse_item(input)?;
+        *input = input.trim_start();
+
+        if let Some(rest) = input.strip_prefix(',') {
+            *input = rest.trim_start();
+        } else if let Some(rest) = input.strip_prefix(']') {
+            *input = rest;
+            return Ok(());
+        } else {
+            return Err(CodecError::Parse(format!(
+                "expected ',' or ']', found {:?}",
+                input.chars().next()
+            )));
+        }
+    }
+}
+
+fn expect(input: &mut &str, expected: char) -> Result<(), CodecError> {
+    match input.strip_prefix(expected) {
+        Some(rest) => {
+            *input = rest;
+            Ok(())
+        }
+        None => Err(CodecError::Parse(format!(
+            "expected {:?}, found {:?}",
+            expected,
+            input.chars().next()
+        ))),
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::VecDeque;
+
+use super::family::{PointerFamily, RcFamily};
+use super::DoublyLinkedList;
+
+// Moves the values out front to back, freeing each node as it goes.
+pub struct IntoIter<T, F: PointerFamily = RcFamily> {
+    list: DoublyLinkedList<T, F>,
+}
+
+impl<T, F: PointerFamily> Iterator for IntoIter<T, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let head = self.list.head.clone()?;
+        Some(self.list.unlink(head))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.count as usize;
+        (len, Some(len))
+    }
+}
+
+impl<T, F: PointerFamily> DoubleEndedIterator for IntoIter<T, F> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_last()
+    }
+}
+
+impl<T, F: PointerFamily> ExactSizeIterator for IntoIter<T, F> {}
+
+impl<T, F: PointerFamily> IntoIterator for DoublyLinkedList<T, F> {
+    type Item = T;
+    type IntoIter = IntoIter<T, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+impl<T, F: PointerFamily> FromIterator<T> for DoublyLinkedList<T, F> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = DoublyLinkedList::default();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T, F: PointerFamily> Extend<T> for DoublyLinkedList<T, F> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.append(value);
+        }
+    }
+}
+
+// Only for the `Rc` family, like `new`: with a single impl,
+// `DoublyLinkedList::from(vec)` infers the family instead of needing an
+// annotation. Other families can `collect()` into their alias.
+impl<T> From<Vec<T>> for DoublyLinkedList<T> {
+    fn from(values: Vec<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T> From<VecDeque<T>> for DoublyLinkedList<T> {
+    fn from(values: VecDeque<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T, F: PointerFamily> From<DoublyLinkedList<T, F>> for Vec<T> {
+    fn from(list: DoublyLinkedList<T, F>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T, F: PointerFamily> From<DoublyLinkedList<T, F>> for VecDeque<T> {
+    fn from(list: DoublyLinkedList<T, F>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+// A deep copy: new nodes holding clones of the values.
+impl<T: Clone, F: PointerFamily> Clone for DoublyLinkedList<T, F> {
+    fn clone(&self) -> Self {
+        let mut list = DoublyLinkedList::default();
+        let mut cursor = self.head.clone();
+
+        while let Some(node) = cursor {
+            let current = F::borrow(&node);
+            list.append(current.value.clone().expect("Node without a value"));
+            cursor = current.next.clone();
+        }
+        list
+    }
+}
+
+impl<T, F: PointerFamily> DoublyLinkedList<T, F> {
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut values = Vec::with_capacity(self.count as usize);
+        let mut cursor = self.head.clone();
+
+        while let Some(node) = cursor {
+            let current = F::borrow(&node);
+            values.push(current.value.clone().expect("Node without a value"));
+            cursor = current.next.clone();
+        }
+        values
+    }
+}
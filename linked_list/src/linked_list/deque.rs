@@ -0,0 +1,46 @@
+use crate::deque::Deque;
+
+use super::family::PointerFamily;
+use super::DoublyLinkedList;
+
+impl<T, F: PointerFamily> Deque for DoublyLinkedList<T, F> {
+    type Item = T;
+
+    fn push_front(&mut self, value: T) {
+        DoublyLinkedList::push_front(self, value);
+    }
+
+    fn push_back(&mut self, value: T) {
+        self.append(value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        DoublyLinkedList::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        self.pop_last()
+    }
+
+    fn peek_front_with<R, P>(&self, f: P) -> Option<R>
+    where
+        P: FnOnce(&T) -> R,
+    {
+        let head = self.head.as_ref()?;
+        let node = F::borrow(head);
+        Some(f(node.value.as_ref().expect("Node without a value")))
+    }
+
+    fn peek_back_with<R, P>(&self, f: P) -> Option<R>
+    where
+        P: FnOnce(&T) -> R,
+    {
+        let tail = self.tail.as_ref().and_then(F::upgrade)?;
+        let node = F::borrow(&tail);
+        Some(f(node.value.as_ref().expect("Node without a value")))
+    }
+
+    fn len(&self) -> usize {
+        self.count as usize
+    }
+}
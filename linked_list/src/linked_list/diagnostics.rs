@@ -0,0 +1,70 @@
+use std::rc::Rc;
+
+use super::node::Node;
+use super::DoublyLinkedList;
+use crate::diagnostics::{self, Diagnose, Diagnostics, DropWatch, NodeCounts};
+
+// Each node should be owned by exactly one strong pointer (`head` or the
+// previous node's `next`) and referenced by exactly one weak pointer (the
+// next node's `prev`, or `tail` for the last node).
+impl<T> Diagnose for DoublyLinkedList<T> {
+    type Node = Node<T>;
+
+    fn diagnostics(&self) -> Diagnostics {
+        let mut nodes: Vec<NodeCounts> = Vec::new();
+        let mut prev: Option<Rc<_>> = None;
+        let mut cursor = self.head.clone();
+        let mut index = 0;
+
+        while let Some(node) = cursor {
+            // A `next` chain longer than `count` can only be a cycle, which
+            // would otherwise keep this loop going forever.
+            if index == self.count {
+                if let Some(counts) = nodes.last_mut() {
+                    counts
+                        .issues
+                        .push("next chain is longer than the list count: cycle".to_owned());
+                }
+                break;
+            }
+
+            let mut counts = diagnostics::check_counts(index, &node, 1, 1);
+
+            let prev_matches = match (&node.borrow().prev, &prev) {
+                (None, None) => true,
+                (Some(prev_weak), Some(prev_node)) => prev_weak
+                    .upgrade()
+                    .is_some_and(|linked| Rc::ptr_eq(&linked, prev_node)),
+                _ => false,
+            };
+            if !prev_matches {
+                counts
+                    .issues
+                    .push("prev does not point at the previous node".to_owned());
+            }
+
+            nodes.push(counts);
+            cursor = node.borrow().next.clone();
+            prev = Some(node);
+            index += 1;
+        }
+
+        let tail = self.tail.as_ref().and_then(|tail| tail.upgrade());
+        if let (Some(last), Some(counts)) = (&prev, nodes.last_mut()) {
+            if !tail.is_some_and(|tail| Rc::ptr_eq(&tail, last)) {
+                counts
+                    .issues
+                    .push("tail does not point at the last node".to_owned());
+            }
+        }
+
+        Diagnostics {
+            name: "DoublyLinkedList",
+            nodes,
+        }
+    }
+
+    fn watch_nodes(&self) -> DropWatch<Node<T>> {
+        DropWatch::new(self.iter().map(|node| Rc::downgrade(&node)).collect())
+    }
+}
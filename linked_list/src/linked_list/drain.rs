@@ -0,0 +1,72 @@
+use std::{ops::RangeBounds, rc::Rc};
+
+use super::error::ListError;
+use super::DoublyLinkedList;
+
+// Owns the sub-chain detached by `DoublyLinkedList::drain`. The range has
+// already left the source list, so dropping a partly consumed `Drain` just
+// frees whatever is left.
+pub struct Drain<T> {
+    list: DoublyLinkedList<T>,
+}
+
+impl<T> Iterator for Drain<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let head = self.list.head.clone()?;
+        Some(self.list.unlink(head))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.count as usize;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Drain<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let tail = self.list.tail.as_ref()?.upgrade()?;
+        Some(self.list.unlink(tail))
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<T> {}
+
+impl<T> DoublyLinkedList<T> {
+    pub fn drain<R>(&mut self, range: R) -> Result<Drain<T>, ListError>
+    where
+        R: RangeBounds<u32>,
+    {
+        self.remove_range(range).map(|list| Drain { list })
+    }
+
+    pub fn remove_range<R>(&mut self, range: R) -> Result<DoublyLinkedList<T>, ListError>
+    where
+        R: RangeBounds<u32>,
+    {
+        let (start, end) = self.resolve_range(range)?;
+        if start == end {
+            return Ok(DoublyLinkedList::new());
+        }
+
+        let first = self.node_at(start)?;
+        let last = self.node_at(end - 1)?;
+
+        let before = first
+            .borrow_mut()
+            .prev
+            .take()
+            .and_then(|prev_weak| prev_weak.upgrade());
+        let after = last.borrow_mut().next.take();
+
+        self.link(before.as_ref(), after);
+        self.count -= end - start;
+
+        Ok(DoublyLinkedList {
+            count: end - start,
+            head: Some(first),
+            tail: Some(Rc::downgrade(&last)),
+        })
+    }
+}
@@ -0,0 +1,58 @@
+use std::{
+    cell::{Ref, RefCell, RefMut},
+    marker::PhantomData,
+    rc::Rc,
+};
+
+use super::node::Node;
+
+// The value lives behind the node's RefCell, so a plain `&T` can't outlive the
+// traversal that found it. These handles keep the node alive and hand out
+// `Ref`/`RefMut` guards on demand, while the lifetime ties them to the list
+// borrow so the list can't be changed underneath them.
+
+pub struct ElementRef<'a, T> {
+    node: Rc<RefCell<Node<T>>>,
+    _list: PhantomData<&'a T>,
+}
+
+impl<'a, T> ElementRef<'a, T> {
+    pub(crate) fn new(node: Rc<RefCell<Node<T>>>) -> Self {
+        ElementRef {
+            node,
+            _list: PhantomData,
+        }
+    }
+
+    pub fn borrow(&self) -> Ref<'_, T> {
+        Ref::map(self.node.borrow(), |node| {
+            node.value.as_ref().expect("Node without a value")
+        })
+    }
+}
+
+pub struct ElementMut<'a, T> {
+    node: Rc<RefCell<Node<T>>>,
+    _list: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> ElementMut<'a, T> {
+    pub(crate) fn new(node: Rc<RefCell<Node<T>>>) -> Self {
+        ElementMut {
+            node,
+            _list: PhantomData,
+        }
+    }
+
+    pub fn borrow(&self) -> Ref<'_, T> {
+        Ref::map(self.node.borrow(), |node| {
+            node.value.as_ref().expect("Node without a value")
+        })
+    }
+
+    pub fn borrow_mut(&mut self) -> RefMut<'_, T> {
+        RefMut::map(self.node.borrow_mut(), |node| {
+            node.value.as_mut().expect("Node without a value")
+        })
+    }
+}
@@ -0,0 +1,30 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListError {
+    IndexOutOfBounds { index: u32, len: u32 },
+    InvalidRange { start: u32, end: u32, len: u32 },
+}
+
+impl fmt::Display for ListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ListError::IndexOutOfBounds { index, len } => {
+                write!(
+                    f,
+                    "index {} out of bounds for list of length {}",
+                    index, len
+                )
+            }
+            ListError::InvalidRange { start, end, len } => {
+                write!(
+                    f,
+                    "range {}..{} is invalid for list of length {}",
+                    start, end, len
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ListError {}
@@ -0,0 +1,115 @@
+use std::{
+    cell::{Ref, RefCell, RefMut},
+    ops::{Deref, DerefMut},
+    rc::{self, Rc},
+    sync::{self, Arc, Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+// Picks the shared pointer, weak pointer and interior-mutability cell that
+// nodes are linked with, so the same list code can be single-threaded
+// (`Rc` + `RefCell`) or shareable across threads (`Arc` + a lock).
+pub trait PointerFamily {
+    type Pointer<T>: Clone;
+    type WeakPointer<T>: Clone;
+    type Guard<'a, T: 'a>: Deref<Target = T>;
+    type GuardMut<'a, T: 'a>: DerefMut<Target = T>;
+
+    fn new<T>(value: T) -> Self::Pointer<T>;
+
+    fn downgrade<T>(pointer: &Self::Pointer<T>) -> Self::WeakPointer<T>;
+
+    fn upgrade<T>(weak: &Self::WeakPointer<T>) -> Option<Self::Pointer<T>>;
+
+    fn borrow<T>(pointer: &Self::Pointer<T>) -> Self::Guard<'_, T>;
+
+    fn borrow_mut<T>(pointer: &Self::Pointer<T>) -> Self::GuardMut<'_, T>;
+}
+
+pub struct RcFamily;
+
+impl PointerFamily for RcFamily {
+    type Pointer<T> = Rc<RefCell<T>>;
+    type WeakPointer<T> = rc::Weak<RefCell<T>>;
+    type Guard<'a, T: 'a> = Ref<'a, T>;
+    type GuardMut<'a, T: 'a> = RefMut<'a, T>;
+
+    fn new<T>(value: T) -> Self::Pointer<T> {
+        Rc::new(RefCell::new(value))
+    }
+
+    fn downgrade<T>(pointer: &Self::Pointer<T>) -> Self::WeakPointer<T> {
+        Rc::downgrade(pointer)
+    }
+
+    fn upgrade<T>(weak: &Self::WeakPointer<T>) -> Option<Self::Pointer<T>> {
+        weak.upgrade()
+    }
+
+    fn borrow<T>(pointer: &Self::Pointer<T>) -> Self::Guard<'_, T> {
+        pointer.borrow()
+    }
+
+    fn borrow_mut<T>(pointer: &Self::Pointer<T>) -> Self::GuardMut<'_, T> {
+        pointer.borrow_mut()
+    }
+}
+
+// A poisoned lock only means another thread panicked mid-operation; the
+// guards are still handed out so the list can at least be dropped.
+pub struct ArcMutexFamily;
+
+impl PointerFamily for ArcMutexFamily {
+    type Pointer<T> = Arc<Mutex<T>>;
+    type WeakPointer<T> = sync::Weak<Mutex<T>>;
+    type Guard<'a, T: 'a> = MutexGuard<'a, T>;
+    type GuardMut<'a, T: 'a> = MutexGuard<'a, T>;
+
+    fn new<T>(value: T) -> Self::Pointer<T> {
+        Arc::new(Mutex::new(value))
+    }
+
+    fn downgrade<T>(pointer: &Self::Pointer<T>) -> Self::WeakPointer<T> {
+        Arc::downgrade(pointer)
+    }
+
+    fn upgrade<T>(weak: &Self::WeakPointer<T>) -> Option<Self::Pointer<T>> {
+        weak.upgrade()
+    }
+
+    fn borrow<T>(pointer: &Self::Pointer<T>) -> Self::Guard<'_, T> {
+        pointer.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn borrow_mut<T>(pointer: &Self::Pointer<T>) -> Self::GuardMut<'_, T> {
+        pointer.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+pub struct ArcRwLockFamily;
+
+impl PointerFamily for ArcRwLockFamily {
+    type Pointer<T> = Arc<RwLock<T>>;
+    type WeakPointer<T> = sync::Weak<RwLock<T>>;
+    type Guard<'a, T: 'a> = RwLockReadGuard<'a, T>;
+    type GuardMut<'a, T: 'a> = RwLockWriteGuard<'a, T>;
+
+    fn new<T>(value: T) -> Self::Pointer<T> {
+        Arc::new(RwLock::new(value))
+    }
+
+    fn downgrade<T>(pointer: &Self::Pointer<T>) -> Self::WeakPointer<T> {
+        Arc::downgrade(pointer)
+    }
+
+    fn upgrade<T>(weak: &Self::WeakPointer<T>) -> Option<Self::Pointer<T>> {
+        weak.upgrade()
+    }
+
+    fn borrow<T>(pointer: &Self::Pointer<T>) -> Self::Guard<'_, T> {
+        pointer.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn borrow_mut<T>(pointer: &Self::Pointer<T>) -> Self::GuardMut<'_, T> {
+        pointer.write().unwrap_or_else(PoisonError::into_inner)
+    }
+}
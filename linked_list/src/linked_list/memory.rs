@@ -0,0 +1,55 @@
+use std::{
+    cell::RefCell,
+    mem,
+    rc::{Rc, Weak},
+};
+
+use super::node::Node;
+use super::DoublyLinkedList;
+use crate::memory::{self, MemoryUsage};
+
+impl<T> DoublyLinkedList<T> {
+    // Every node is one `Rc` allocation laid out as
+    // `{ strong, weak, RefCell { borrow flag, Node { value, prev, next } } }`.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        let word = mem::size_of::<usize>();
+        let cell_align = mem::align_of::<RefCell<Node<T>>>();
+        let node_align = cell_align.max(mem::align_of::<usize>());
+        let node_bytes = round_up(
+            round_up(2 * word, cell_align) + mem::size_of::<RefCell<Node<T>>>(),
+            node_align,
+        );
+
+        let breakdown = vec![
+            ("Rc strong count", word),
+            ("Rc weak count", word),
+            ("RefCell borrow flag", mem::size_of::<isize>()),
+            ("value", mem::size_of::<T>()),
+            (
+                "Option<T> overhead (discriminant + padding)",
+                mem::size_of::<Option<T>>() - mem::size_of::<T>(),
+            ),
+            (
+                "prev: Option<Weak>",
+                mem::size_of::<Option<Weak<RefCell<Node<T>>>>>(),
+            ),
+            (
+                "next: Option<Rc>",
+                mem::size_of::<Option<Rc<RefCell<Node<T>>>>>(),
+            ),
+        ];
+
+        MemoryUsage {
+            name: "DoublyLinkedList<T>".to_owned(),
+            elements: self.count as usize,
+            allocated: self.count as usize,
+            header_bytes: mem::size_of::<Self>(),
+            bytes_per_slot: node_bytes,
+            breakdown: memory::with_padding(breakdown, node_bytes),
+        }
+    }
+}
+
+fn round_up(bytes: usize, align: usize) -> usize {
+    bytes.div_ceil(align) * align
+}
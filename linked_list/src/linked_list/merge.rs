@@ -0,0 +1,120 @@
+use std::cmp::Ordering;
+
+use super::DoublyLinkedList;
+
+// All of these expect both inputs to be sorted and reuse the input nodes for
+// the result instead of allocating new ones.
+impl<T> DoublyLinkedList<T> {
+    pub fn merge<F>(mut self, mut other: DoublyLinkedList<T>, mut cmp: F) -> DoublyLinkedList<T>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut result = DoublyLinkedList::new();
+
+        while let Some(order) = compare_fronts(&self, &other, &mut cmp) {
+            let node = match order {
+                Ordering::Greater => other.detach_front(),
+                _ => self.detach_front(),
+            };
+            result.attach_back(node.expect("Compared list has a front"));
+        }
+
+        result.attach_list(self);
+        result.attach_list(other);
+        result
+    }
+
+    pub fn union(mut self, mut other: DoublyLinkedList<T>) -> DoublyLinkedList<T>
+    where
+        T: Ord,
+    {
+        let mut result = DoublyLinkedList::new();
+
+        while let Some(order) = compare_fronts(&self, &other, T::cmp) {
+            let node = match order {
+                Ordering::Less => self.detach_front(),
+                Ordering::Greater => other.detach_front(),
+                Ordering::Equal => {
+                    other.detach_front();
+                    self.detach_front()
+                }
+            };
+            result.attach_back(node.expect("Compared list has a front"));
+        }
+
+        result.attach_list(self);
+        result.attach_list(other);
+        result
+    }
+
+    pub fn intersection(mut self, mut other: DoublyLinkedList<T>) -> DoublyLinkedList<T>
+    where
+        T: Ord,
+    {
+        let mut result = DoublyLinkedList::new();
+
+        while let Some(order) = compare_fronts(&self, &other, T::cmp) {
+            match order {
+                Ordering::Less => {
+                    self.detach_front();
+                }
+                Ordering::Greater => {
+                    other.detach_front();
+                }
+                Ordering::Equal => {
+                    other.detach_front();
+                    let node = self.detach_front();
+                    result.attach_back(node.expect("Compared list has a front"));
+                }
+            }
+        }
+
+        result
+    }
+
+    // Elements of `self` that are not in `other`.
+    pub fn difference(mut self, mut other: DoublyLinkedList<T>) -> DoublyLinkedList<T>
+    where
+        T: Ord,
+    {
+        let mut result = DoublyLinkedList::new();
+
+        while let Some(order) = compare_fronts(&self, &other, T::cmp) {
+            match order {
+                Ordering::Less => {
+                    let node = self.detach_front();
+                    result.attach_back(node.expect("Compared list has a front"));
+                }
+                Ordering::Greater => {
+                    other.detach_front();
+                }
+                Ordering::Equal => {
+                    self.detach_front();
+                    other.detach_front();
+                }
+            }
+        }
+
+        result.attach_list(self);
+        result
+    }
+}
+
+// Compares the first elements of both lists, or returns `None` once either
+// of them runs out.
+fn compare_fronts<T, F>(
+    left: &DoublyLinkedList<T>,
+    right: &DoublyLinkedList<T>,
+    mut cmp: F,
+) -> Option<Ordering>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let left_head = left.head.as_ref()?.borrow();
+    let right_head = right.head.as_ref()?.borrow();
+
+    Some(cmp(
+        left_head.value.as_ref().expect("Node without a value"),
+        right_head.value.as_ref().expect("Node without a value"),
+    ))
+}
@@ -1,18 +1,26 @@
 use std::cell::RefCell;
-use std::rc::{Rc, Weak};
+use std::rc::Rc;
 
-pub struct Node<T> {
+use super::family::{PointerFamily, RcFamily};
+
+pub struct Node<T, F: PointerFamily = RcFamily> {
     pub value: Option<T>,
-    pub prev: Option<Weak<RefCell<Node<T>>>>,
-    pub next: Option<Rc<RefCell<Node<T>>>>,
+    pub prev: Option<F::WeakPointer<Node<T, F>>>,
+    pub next: Option<F::Pointer<Node<T, F>>>,
 }
 
 impl<T> Node<T> {
     pub fn new(value: T) -> Rc<RefCell<Self>> {
-        Rc::new(RefCell::new(Node {
+        Self::new_in(value)
+    }
+}
+
+impl<T, F: PointerFamily> Node<T, F> {
+    pub fn new_in(value: T) -> F::Pointer<Self> {
+        F::new(Node {
             value: Some(value),
             prev: None,
             next: None,
-        }))
+        })
     }
 }
@@ -0,0 +1,50 @@
+use std::rc::{Rc, Weak};
+
+use super::DoublyLinkedList;
+
+impl<T> DoublyLinkedList<T> {
+    // Moves the first `k` elements to the back, only by relinking the ends.
+    pub fn rotate_left(&mut self, k: u32) {
+        if self.count == 0 {
+            return;
+        }
+
+        let k = k % self.count;
+        if k == 0 {
+            return;
+        }
+
+        let head = self.head.clone().expect("Non-empty list without a head");
+        let tail = self
+            .tail
+            .as_ref()
+            .and_then(Weak::upgrade)
+            .expect("Non-empty list without a tail");
+
+        // Close the chain into a ring so the new ends are just neighbours.
+        tail.borrow_mut().next = Some(Rc::clone(&head));
+        head.borrow_mut().prev = Some(Rc::downgrade(&tail));
+
+        let new_head = self
+            .node_at(k)
+            .expect("Rotation index is always below the length");
+        let new_tail = new_head
+            .borrow_mut()
+            .prev
+            .take()
+            .and_then(|prev_weak| prev_weak.upgrade())
+            .expect("Ring node without a predecessor");
+
+        new_tail.borrow_mut().next = None;
+        self.tail = Some(Rc::downgrade(&new_tail));
+        self.head = Some(new_head);
+    }
+
+    pub fn rotate_right(&mut self, k: u32) {
+        if self.count == 0 {
+            return;
+        }
+
+        self.rotate_left(self.count - k % self.count);
+    }
+}
@@ -0,0 +1,99 @@
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+};
+
+use super::node::Node;
+use super::DoublyLinkedList;
+
+impl<T> DoublyLinkedList<T> {
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.find_node(|item| item == value).is_some()
+    }
+
+    pub fn position<P>(&self, predicate: P) -> Option<u32>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.find_node(predicate).map(|(index, _)| index)
+    }
+
+    pub fn rposition<P>(&self, mut predicate: P) -> Option<u32>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let mut cursor = self.tail.as_ref().and_then(Weak::upgrade);
+        let mut index = self.count;
+
+        while let Some(node) = cursor {
+            index -= 1;
+            if predicate(node.borrow().value.as_ref().expect("Node without a value")) {
+                return Some(index);
+            }
+            cursor = node.borrow().prev.as_ref().and_then(Weak::upgrade);
+        }
+        None
+    }
+
+    pub fn find_map<B, F>(&self, mut f: F) -> Option<B>
+    where
+        F: FnMut(&T) -> Option<B>,
+    {
+        let mut cursor = self.head.clone();
+
+        while let Some(node) = cursor {
+            if let Some(found) = f(node.borrow().value.as_ref().expect("Node without a value")) {
+                return Some(found);
+            }
+            cursor = node.borrow().next.clone();
+        }
+        None
+    }
+
+    pub fn remove_first(&mut self, value: &T) -> Option<T>
+    where
+        T: PartialEq,
+    {
+        let (_, node) = self.find_node(|item| item == value)?;
+        Some(self.unlink(node))
+    }
+
+    // Returns how many elements were removed.
+    pub fn remove_all(&mut self, value: &T) -> u32
+    where
+        T: PartialEq,
+    {
+        let mut cursor = self.head.clone();
+        let mut removed = 0;
+
+        while let Some(node) = cursor {
+            cursor = node.borrow().next.clone();
+            let matches = node.borrow().value.as_ref() == Some(value);
+            if matches {
+                self.unlink(node);
+                removed += 1;
+            }
+        }
+        removed
+    }
+
+    pub(super) fn find_node<P>(&self, mut predicate: P) -> Option<(u32, Rc<RefCell<Node<T>>>)>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let mut cursor = self.head.clone();
+        let mut index = 0;
+
+        while let Some(node) = cursor {
+            if predicate(node.borrow().value.as_ref().expect("Node without a value")) {
+                return Some((index, node));
+            }
+            cursor = node.borrow().next.clone();
+            index += 1;
+        }
+        None
+    }
+}
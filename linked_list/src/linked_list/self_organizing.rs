@@ -0,0 +1,171 @@
+use std::{
+    cell::{Ref, RefCell, RefMut},
+    marker::PhantomData,
+    rc::{Rc, Weak},
+};
+
+use super::node::Node;
+use super::DoublyLinkedList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromotionPolicy {
+    // A hit jumps straight to the front.
+    MoveToFront,
+    // A hit swaps places with its predecessor.
+    Transpose,
+    // Elements stay ordered by hit count, most looked up first; ties keep
+    // their current order.
+    FrequencyCount,
+}
+
+struct Entry<T> {
+    value: T,
+    hits: u32,
+}
+
+type Link<T> = Rc<RefCell<Node<Entry<T>>>>;
+
+// A list whose lookups reorder it, so that frequently found elements end up
+// near the front and are found faster next time. Promotion only relinks the
+// hit node through its `prev` pointer: O(1) for `MoveToFront` and
+// `Transpose`, while `FrequencyCount` steps back past each neighbour with
+// fewer hits.
+pub struct SelfOrganizingList<T> {
+    list: DoublyLinkedList<Entry<T>>,
+    policy: PromotionPolicy,
+}
+
+// The element returned by `find`. Holding it keeps the list borrowed.
+pub struct Found<'a, T> {
+    node: Link<T>,
+    _list: PhantomData<&'a T>,
+}
+
+impl<T> Found<'_, T> {
+    pub fn borrow(&self) -> Ref<'_, T> {
+        Ref::map(self.node.borrow(), |node| &entry_of(node).value)
+    }
+
+    pub fn hits(&self) -> u32 {
+        entry_of(&self.node.borrow()).hits
+    }
+}
+
+impl<T> SelfOrganizingList<T> {
+    pub fn new(policy: PromotionPolicy) -> Self {
+        SelfOrganizingList {
+            list: DoublyLinkedList::new(),
+            policy,
+        }
+    }
+
+    pub fn policy(&self) -> PromotionPolicy {
+        self.policy
+    }
+
+    pub fn len(&self) -> u32 {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    // New elements start at the back with no hits.
+    pub fn insert(&mut self, value: T) {
+        self.list.append(Entry { value, hits: 0 });
+    }
+
+    // Finds the first match from the front and promotes it.
+    pub fn find<P>(&mut self, mut predicate: P) -> Option<Found<'_, T>>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let (_, node) = self.list.find_node(|entry| predicate(&entry.value))?;
+        // Saturates: wrapping to 0 would drop the hottest element to the back.
+        let hits = entry_of(&node.borrow()).hits;
+        entry_mut(&node).hits = hits.saturating_add(1);
+        self.promote(&node);
+
+        Some(Found {
+            node,
+            _list: PhantomData,
+        })
+    }
+
+    pub fn contains(&mut self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.find(|item| item == value).is_some()
+    }
+
+    // Removes the first match without counting it as a hit.
+    pub fn remove<P>(&mut self, mut predicate: P) -> Option<T>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let (_, node) = self.list.find_node(|entry| predicate(&entry.value))?;
+        Some(self.list.unlink(node).value)
+    }
+
+    // The current order, front first.
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.list
+            .iter()
+            .map(|node| entry_of(&node.borrow()).value.clone())
+            .collect()
+    }
+
+    fn promote(&mut self, node: &Link<T>) {
+        let Some(prev) = prev_of(node) else {
+            return;
+        };
+
+        let next = match self.policy {
+            PromotionPolicy::MoveToFront => {
+                self.list.detach(node);
+                self.list.attach_front(Rc::clone(node));
+                return;
+            }
+            PromotionPolicy::Transpose => prev,
+            PromotionPolicy::FrequencyCount => {
+                let hits = entry_of(&node.borrow()).hits;
+                let mut next = None;
+                let mut cursor = Some(prev);
+                while let Some(candidate) = cursor {
+                    if entry_of(&candidate.borrow()).hits >= hits {
+                        break;
+                    }
+                    cursor = prev_of(&candidate);
+                    next = Some(candidate);
+                }
+
+                let Some(next) = next else {
+                    return;
+                };
+                next
+            }
+        };
+
+        self.list.detach(node);
+        self.list.attach_before(Rc::clone(node), &next);
+    }
+}
+
+fn prev_of<T>(node: &Link<T>) -> Option<Link<T>> {
+    node.borrow().prev.as_ref().and_then(Weak::upgrade)
+}
+
+fn entry_of<T>(node: &Node<Entry<T>>) -> &Entry<T> {
+    node.value.as_ref().expect("Node without a value")
+}
+
+fn entry_mut<T>(node: &Link<T>) -> RefMut<'_, Entry<T>> {
+    RefMut::map(node.borrow_mut(), |node| {
+        node.value.as_mut().expect("Node without a value")
+    })
+}
@@ -0,0 +1,58 @@
+use super::{DoublyLinkedList, ElementRef, PointerFamily};
+use crate::random::XorShift;
+
+impl<T, F: PointerFamily> DoublyLinkedList<T, F> {
+    // Fisher-Yates over the nodes themselves: every node is detached, the
+    // handles are shuffled, and the nodes are linked back in the new order.
+    // Values never move, so handles to nodes stay attached to their values.
+    pub fn shuffle(&mut self, rng: &mut XorShift) {
+        let mut nodes = Vec::with_capacity(self.count as usize);
+        while let Some(node) = self.detach_front() {
+            nodes.push(node);
+        }
+
+        for i in (1..nodes.len()).rev() {
+            let j = rng.below(i as u64 + 1) as usize;
+            nodes.swap(i, j);
+        }
+
+        for node in nodes {
+            self.attach_back(node);
+        }
+    }
+}
+
+impl<T> DoublyLinkedList<T> {
+    pub fn choose(&self, rng: &mut XorShift) -> Option<ElementRef<'_, T>> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let index = rng.below(u64::from(self.count)) as u32;
+        Some(
+            self.get(index)
+                .expect("Random index is always below the length"),
+        )
+    }
+
+    // Reservoir sampling: picks `k` distinct elements (all of them if the
+    // list is shorter) in one pass, without knowing the length up front.
+    // The sample comes back in reservoir order, not list order.
+    pub fn sample(&self, k: u32, rng: &mut XorShift) -> Vec<ElementRef<'_, T>> {
+        let k = k as usize;
+        let mut reservoir = Vec::with_capacity(k.min(self.count as usize));
+
+        for (seen, node) in self.iter().enumerate() {
+            if seen < k {
+                reservoir.push(ElementRef::new(node));
+                continue;
+            }
+
+            let slot = rng.below(seen as u64 + 1) as usize;
+            if slot < k {
+                reservoir[slot] = ElementRef::new(node);
+            }
+        }
+        reservoir
+    }
+}
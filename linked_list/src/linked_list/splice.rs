@@ -0,0 +1,60 @@
+use std::ops::RangeBounds;
+
+use super::error::ListError;
+use super::DoublyLinkedList;
+
+impl<T> DoublyLinkedList<T> {
+    // Unlike `insert_to`, `index` may equal the length to insert at the end.
+    pub fn insert_many<I>(&mut self, index: u32, values: I) -> Result<(), ListError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.splice(index..index, values).map(|_| ())
+    }
+
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Result<Vec<T>, ListError>
+    where
+        R: RangeBounds<u32>,
+        I: IntoIterator<Item = T>,
+    {
+        let (start, end) = self.resolve_range(range)?;
+
+        // `replace_with` is user code and may panic, so it runs before the
+        // list is touched; the cut and relink below can't fail halfway.
+        let mut replacement: DoublyLinkedList<T> = replace_with.into_iter().collect();
+
+        let before = match start {
+            0 => None,
+            _ => Some(self.node_at(start - 1)?),
+        };
+        let mut cursor = match &before {
+            Some(node) => node.borrow().next.clone(),
+            None => self.head.clone(),
+        };
+
+        let mut removed = Vec::with_capacity((end - start) as usize);
+        for _ in start..end {
+            let node = cursor.expect("List shorter than its count");
+            let mut node = node.borrow_mut();
+
+            cursor = node.next.take();
+            node.prev = None;
+            removed.push(node.value.take().expect("Node without a value"));
+        }
+        let after = cursor;
+
+        match replacement.head.take() {
+            Some(first) => {
+                let last = replacement.tail.take().and_then(|tail| tail.upgrade());
+                let last = last.expect("Non-empty list without a tail");
+                self.link(before.as_ref(), Some(first));
+                self.link(Some(&last), after);
+            }
+            None => self.link(before.as_ref(), after),
+        }
+
+        self.count = self.count - (end - start) + replacement.count;
+        replacement.count = 0;
+        Ok(removed)
+    }
+}
@@ -0,0 +1,176 @@
+use std::{
+    cell::RefCell,
+    marker::PhantomData,
+    ops::RangeBounds,
+    rc::{Rc, Weak},
+};
+
+use super::element::ElementRef;
+use super::error::ListError;
+use super::node::Node;
+use super::DoublyLinkedList;
+
+type Link<T> = Rc<RefCell<Node<T>>>;
+
+// A window over `len` consecutive nodes of a list, starting at `first` and
+// ending at `last`. Both ends are found once when the view is made; after
+// that every operation walks inside the window only. The lifetime keeps the
+// parent list borrowed, so its links can't change while the view exists.
+pub struct ListView<'a, T> {
+    first: Option<Link<T>>,
+    last: Option<Link<T>>,
+    len: u32,
+    _list: PhantomData<&'a DoublyLinkedList<T>>,
+}
+
+pub struct ViewIter<'a, T> {
+    front: Option<Link<T>>,
+    back: Option<Link<T>>,
+    remaining: u32,
+    _list: PhantomData<&'a DoublyLinkedList<T>>,
+}
+
+impl<T> Iterator for ViewIter<'_, T> {
+    type Item = Link<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let node = self.front.take()?;
+        self.front = node.borrow().next.clone();
+        Some(node)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining as usize;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for ViewIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let node = self.back.take()?;
+        self.back = node.borrow().prev.as_ref().and_then(Weak::upgrade);
+        Some(node)
+    }
+}
+
+impl<T> ExactSizeIterator for ViewIter<'_, T> {}
+
+impl<T> DoublyLinkedList<T> {
+    pub fn view<R>(&self, range: R) -> Result<ListView<'_, T>, ListError>
+    where
+        R: RangeBounds<u32>,
+    {
+        let (start, end) = self.resolve_range(range)?;
+        let (first, last) = if start == end {
+            (None, None)
+        } else {
+            (Some(self.node_at(start)?), Some(self.node_at(end - 1)?))
+        };
+
+        Ok(ListView {
+            first,
+            last,
+            len: end - start,
+            _list: PhantomData,
+        })
+    }
+}
+
+impl<'a, T> ListView<'a, T> {
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn iter(&self) -> ViewIter<'a, T> {
+        ViewIter {
+            front: self.first.clone(),
+            back: self.last.clone(),
+            remaining: self.len,
+            _list: PhantomData,
+        }
+    }
+
+    // `index` is relative to the start of the view.
+    pub fn get(&self, index: u32) -> Result<ElementRef<'a, T>, ListError> {
+        if index >= self.len {
+            return Err(ListError::IndexOutOfBounds {
+                index,
+                len: self.len,
+            });
+        }
+
+        let node = if index <= self.len / 2 {
+            self.iter().nth(index as usize)
+        } else {
+            self.iter().nth_back((self.len - 1 - index) as usize)
+        };
+        Ok(ElementRef::new(node.expect("View shorter than its length")))
+    }
+
+    pub fn first(&self) -> Result<ElementRef<'a, T>, ListError> {
+        self.get(0)
+    }
+
+    pub fn last(&self) -> Result<ElementRef<'a, T>, ListError> {
+        self.get(self.len.saturating_sub(1))
+    }
+
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.position(|item| item == value).is_some()
+    }
+
+    pub fn position<P>(&self, mut predicate: P) -> Option<u32>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .position(|node| predicate(value_of(&node.borrow())))
+            .map(|index| index as u32)
+    }
+
+    pub fn rposition<P>(&self, mut predicate: P) -> Option<u32>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .rposition(|node| predicate(value_of(&node.borrow())))
+            .map(|index| index as u32)
+    }
+
+    pub fn find_map<B, F>(&self, mut f: F) -> Option<B>
+    where
+        F: FnMut(&T) -> Option<B>,
+    {
+        self.iter().find_map(|node| f(value_of(&node.borrow())))
+    }
+
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter()
+            .map(|node| value_of(&node.borrow()).clone())
+            .collect()
+    }
+}
+
+fn value_of<T>(node: &Node<T>) -> &T {
+    node.value.as_ref().expect("Node without a value")
+}
@@ -0,0 +1,106 @@
+use std::{collections::VecDeque, fmt, mem};
+
+// Heap and inline bytes used by a container, as laid out by the compiler.
+// Allocator bookkeeping and rounding are not included, so real usage is a
+// little higher for every allocation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryUsage {
+    pub name: String,
+    pub elements: usize,
+    // Slots actually allocated: the capacity for `Vec`, one node per element
+    // for the lists.
+    pub allocated: usize,
+    // Size of the container value itself (head/tail pointers, length...).
+    pub header_bytes: usize,
+    pub bytes_per_slot: usize,
+    // What each slot is made of; the sizes add up to `bytes_per_slot`.
+    pub breakdown: Vec<(&'static str, usize)>,
+}
+
+impl MemoryUsage {
+    pub fn total_bytes(&self) -> usize {
+        self.header_bytes + self.allocated * self.bytes_per_slot
+    }
+
+    // Bytes spent on anything but the element values, per element.
+    pub fn overhead_per_element(&self, value_size: usize) -> f64 {
+        if self.elements == 0 {
+            return 0.0;
+        }
+        let payload = self.elements * value_size;
+        (self.total_bytes() - payload) as f64 / self.elements as f64
+    }
+
+    pub fn of_vec<T>(vec: &Vec<T>) -> Self {
+        MemoryUsage {
+            name: "Vec<T>".to_owned(),
+            elements: vec.len(),
+            allocated: vec.capacity(),
+            header_bytes: mem::size_of::<Vec<T>>(),
+            bytes_per_slot: mem::size_of::<T>(),
+            breakdown: vec![("value", mem::size_of::<T>())],
+        }
+    }
+
+    pub fn of_vec_deque<T>(deque: &VecDeque<T>) -> Self {
+        MemoryUsage {
+            name: "VecDeque<T>".to_owned(),
+            elements: deque.len(),
+            allocated: deque.capacity(),
+            header_bytes: mem::size_of::<VecDeque<T>>(),
+            bytes_per_slot: mem::size_of::<T>(),
+            breakdown: vec![("value", mem::size_of::<T>())],
+        }
+    }
+}
+
+impl fmt::Display for MemoryUsage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{}: {} elements, {} bytes total ({} header + {} x {} bytes)",
+            self.name,
+            self.elements,
+            self.total_bytes(),
+            self.header_bytes,
+            self.allocated,
+            self.bytes_per_slot
+        )?;
+        for (component, bytes) in &self.breakdown {
+            writeln!(f, "    {:<44} {:>4} bytes", component, bytes)?;
+        }
+        Ok(())
+    }
+}
+
+// Inserts a "padding" entry so the breakdown adds up to `slot_bytes`.
+pub fn with_padding(
+    mut breakdown: Vec<(&'static str, usize)>,
+    slot_bytes: usize,
+) -> Vec<(&'static str, usize)> {
+    let used: usize = breakdown.iter().map(|(_, bytes)| bytes).sum();
+    if slot_bytes > used {
+        breakdown.push(("padding", slot_bytes - used));
+    }
+    breakdown
+}
+
+// Prints every report followed by a per-element summary for comparing them.
+pub fn print_comparison(reports: &[MemoryUsage], value_size: usize) {
+    for report in reports {
+        print!("{}", report);
+    }
+    println!();
+    println!(
+        "{:<28} {:>12} {:>16}",
+        "container", "total bytes", "overhead/elem"
+    );
+    for report in reports {
+        println!(
+            "{:<28} {:>12} {:>16.1}",
+            report.name,
+            report.total_bytes(),
+            report.overhead_per_element(value_size)
+        );
+    }
+}
@@ -0,0 +1,36 @@
+// xorshift64: a tiny generator fully determined by its seed, so shuffles
+// and samples can be replayed exactly. Not suitable for anything that has
+// to be unpredictable.
+#[derive(Debug, Clone)]
+pub struct XorShift {
+    state: u64,
+}
+
+impl XorShift {
+    pub fn new(seed: u64) -> Self {
+        // Zero is the one state xorshift never leaves.
+        XorShift { state: seed.max(1) }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+
+    // Uniform in `0..bound`. Draws below `2^64 % bound` are thrown away, so
+    // the remaining range splits evenly and no result is favoured.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "below() needs a non-zero bound");
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let x = self.next_u64();
+            if x >= threshold {
+                return x % bound;
+            }
+        }
+    }
+}
@@ -0,0 +1,329 @@
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    rc::{Rc, Weak},
+};
+
+use crate::diagnostics::{self, Diagnose, Diagnostics, DropWatch};
+use crate::linked_list::{DoublyLinkedList, ListError};
+
+// Same operations as `DoublyLinkedList`, but the list owns one sentinel node
+// that closes the chain into a ring: `sentinel.next` is the head and
+// `sentinel.prev` the tail. Empty and non-empty lists then share one code
+// path, because every real node always has a real neighbour on each side.
+
+pub struct SentinelNode<T> {
+    // `None` only for the sentinel.
+    pub value: Option<T>,
+    pub prev: Weak<RefCell<SentinelNode<T>>>,
+    // Always `Some` once the list is built; it's only an `Option` because
+    // the sentinel has to exist before it can point at itself.
+    pub next: Option<Rc<RefCell<SentinelNode<T>>>>,
+}
+
+type Link<T> = Rc<RefCell<SentinelNode<T>>>;
+
+pub struct SentinelList<T> {
+    count: u32,
+    sentinel: Link<T>,
+}
+
+pub struct SentinelIter<T> {
+    current: Link<T>,
+    sentinel: Link<T>,
+}
+
+impl<T> Iterator for SentinelIter<T> {
+    type Item = Link<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = next_of(&self.current);
+        if Rc::ptr_eq(&next, &self.sentinel) {
+            return None;
+        }
+        self.current = Rc::clone(&next);
+        Some(next)
+    }
+}
+
+impl<T> Default for SentinelList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SentinelList<T> {
+    pub fn new() -> Self {
+        let sentinel = Rc::new_cyclic(|weak| {
+            RefCell::new(SentinelNode {
+                value: None,
+                prev: weak.clone(),
+                next: None,
+            })
+        });
+        sentinel.borrow_mut().next = Some(Rc::clone(&sentinel));
+
+        SentinelList { count: 0, sentinel }
+    }
+
+    pub fn len(&self) -> u32 {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    pub fn iter(&self) -> SentinelIter<T> {
+        SentinelIter {
+            current: Rc::clone(&self.sentinel),
+            sentinel: Rc::clone(&self.sentinel),
+        }
+    }
+
+    pub fn append(&mut self, value: T) {
+        let tail = prev_of(&self.sentinel);
+        self.insert_after(&tail, value);
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        let sentinel = Rc::clone(&self.sentinel);
+        self.insert_after(&sentinel, value);
+    }
+
+    pub fn pop_last(&mut self) -> Option<T> {
+        let tail = prev_of(&self.sentinel);
+        self.unlink(tail)
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head = next_of(&self.sentinel);
+        self.unlink(head)
+    }
+
+    pub fn insert_to(&mut self, index: u32, value: T) -> Result<(), ListError> {
+        let next = self.node_at(index)?;
+        self.insert_after(&prev_of(&next), value);
+        Ok(())
+    }
+
+    pub fn pop_at(&mut self, index: u32) -> Result<T, ListError> {
+        let node = self.node_at(index)?;
+        Ok(self
+            .unlink(node)
+            .expect("Indexed node is never the sentinel"))
+    }
+
+    // Walks from whichever end is closer to `index`.
+    fn node_at(&self, index: u32) -> Result<Link<T>, ListError> {
+        if index >= self.count {
+            return Err(ListError::IndexOutOfBounds {
+                index,
+                len: self.count,
+            });
+        }
+
+        let mut cursor = Rc::clone(&self.sentinel);
+        if index <= self.count / 2 {
+            for _ in 0..=index {
+                cursor = next_of(&cursor);
+            }
+        } else {
+            for _ in index..self.count {
+                cursor = prev_of(&cursor);
+            }
+        }
+        Ok(cursor)
+    }
+
+    fn insert_after(&mut self, prev: &Link<T>, value: T) {
+        let next = next_of(prev);
+        let node = Rc::new(RefCell::new(SentinelNode {
+            value: Some(value),
+            prev: Rc::downgrade(prev),
+            next: Some(Rc::clone(&next)),
+        }));
+
+        next.borrow_mut().prev = Rc::downgrade(&node);
+        prev.borrow_mut().next = Some(node);
+        self.count += 1;
+    }
+
+    // Returns `None` when handed the sentinel, i.e. the list was empty.
+    fn unlink(&mut self, node: Link<T>) -> Option<T> {
+        let value = node.borrow_mut().value.take()?;
+        let prev = prev_of(&node);
+        let next = node.borrow_mut().next.take();
+        let next = next.expect("Ring node without a next link");
+
+        next.borrow_mut().prev = Rc::downgrade(&prev);
+        prev.borrow_mut().next = Some(next);
+        self.count -= 1;
+        Some(value)
+    }
+}
+
+impl<T> Drop for SentinelList<T> {
+    // The ring is a strong cycle through the sentinel, so it has to be cut
+    // by hand; walking it also avoids recursing once per node.
+    fn drop(&mut self) {
+        let mut cursor = self.sentinel.borrow_mut().next.take();
+        while let Some(node) = cursor {
+            cursor = node.borrow_mut().next.take();
+        }
+    }
+}
+
+pub struct SentinelIntoIter<T> {
+    list: SentinelList<T>,
+}
+
+impl<T> Iterator for SentinelIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.count as usize;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for SentinelIntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_last()
+    }
+}
+
+impl<T> ExactSizeIterator for SentinelIntoIter<T> {}
+
+impl<T> IntoIterator for SentinelList<T> {
+    type Item = T;
+    type IntoIter = SentinelIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        SentinelIntoIter { list: self }
+    }
+}
+
+impl<T> FromIterator<T> for SentinelList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = SentinelList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for SentinelList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.append(value);
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for SentinelList<T> {
+    fn from(values: Vec<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T> From<VecDeque<T>> for SentinelList<T> {
+    fn from(values: VecDeque<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T> From<SentinelList<T>> for Vec<T> {
+    fn from(list: SentinelList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> From<SentinelList<T>> for VecDeque<T> {
+    fn from(list: SentinelList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> From<DoublyLinkedList<T>> for SentinelList<T> {
+    fn from(list: DoublyLinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> From<SentinelList<T>> for DoublyLinkedList<T> {
+    fn from(list: SentinelList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> SentinelList<T> {
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter()
+            .map(|node| node.borrow().value.clone().expect("Node without a value"))
+            .collect()
+    }
+}
+
+// Real nodes follow the same rules as in `DoublyLinkedList`: one strong
+// owner (the previous node's `next`) and one weak `prev` pointing back. The
+// sentinel itself is left out of the report.
+impl<T> Diagnose for SentinelList<T> {
+    type Node = SentinelNode<T>;
+
+    fn diagnostics(&self) -> Diagnostics {
+        let mut nodes = Vec::new();
+        let mut prev = Rc::clone(&self.sentinel);
+        let mut index = 0;
+
+        loop {
+            let node = next_of(&prev);
+            if Rc::ptr_eq(&node, &self.sentinel) {
+                break;
+            }
+
+            let mut counts = diagnostics::check_counts(index, &node, 1, 1);
+            let prev_matches = node
+                .borrow()
+                .prev
+                .upgrade()
+                .is_some_and(|linked| Rc::ptr_eq(&linked, &prev));
+            if !prev_matches {
+                counts
+                    .issues
+                    .push("prev does not point at the previous node".to_owned());
+            }
+
+            nodes.push(counts);
+            prev = node;
+            index += 1;
+        }
+
+        Diagnostics {
+            name: "SentinelList",
+            nodes,
+        }
+    }
+
+    fn watch_nodes(&self) -> DropWatch<SentinelNode<T>> {
+        let mut nodes: Vec<_> = self.iter().map(|node| Rc::downgrade(&node)).collect();
+        nodes.push(Rc::downgrade(&self.sentinel));
+        DropWatch::new(nodes)
+    }
+}
+
+fn next_of<T>(node: &Link<T>) -> Link<T> {
+    let next = node.borrow().next.clone();
+    next.expect("Ring node without a next link")
+}
+
+fn prev_of<T>(node: &Link<T>) -> Link<T> {
+    let prev = node.borrow().prev.upgrade();
+    prev.expect("Ring node without a prev link")
+}
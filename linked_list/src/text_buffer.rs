@@ -0,0 +1,278 @@
+use std::{fmt, fs, io, iter, path::Path};
+
+use crate::linked_list::{DoublyLinkedList, ElementRef, ListError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EditError {
+    Index(ListError),
+    InvalidColumn { line: u32, column: usize },
+}
+
+impl fmt::Display for EditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EditError::Index(error) => write!(f, "{}", error),
+            EditError::InvalidColumn { line, column } => {
+                write!(
+                    f,
+                    "column {} is not a valid position in line {}",
+                    column, line
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for EditError {}
+
+impl From<ListError> for EditError {
+    fn from(error: ListError) -> Self {
+        EditError::Index(error)
+    }
+}
+
+// Every edit stored on the undo/redo stacks is the inverse of one that was
+// already applied, so undoing is just applying it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Edit {
+    Insert { line: u32, text: String },
+    Delete { line: u32 },
+    Split { line: u32, column: usize },
+    Join { line: u32 },
+}
+
+// Lines are stored without their terminators. The buffer remembers which
+// terminator the text used and whether the last line had one, so `contents`
+// gives back what was loaded. A file that mixes `\n` and `\r\n` comes back
+// with the terminator of its first line everywhere.
+pub struct TextBuffer {
+    lines: DoublyLinkedList<String>,
+    line_ending: &'static str,
+    trailing_newline: bool,
+    cursor: u32,
+    undo: Vec<Edit>,
+    redo: Vec<Edit>,
+}
+
+impl Default for TextBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TextBuffer {
+    pub fn new() -> Self {
+        TextBuffer {
+            lines: DoublyLinkedList::new(),
+            line_ending: "\n",
+            trailing_newline: true,
+            cursor: 0,
+            undo: Vec::new(),
+            redo: Vec::new(),
+        }
+    }
+
+    pub fn from_text(text: &str) -> Self {
+        let mut buffer = TextBuffer::new();
+        if let Some(end) = text.find('\n') {
+            if text[..end].ends_with('\r') {
+                buffer.line_ending = "\r\n";
+            }
+        }
+        buffer.trailing_newline = text.is_empty() || text.ends_with('\n');
+
+        for line in text.lines() {
+            buffer.lines.append(line.to_owned());
+        }
+        buffer
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        fs::read_to_string(path).map(|text| TextBuffer::from_text(&text))
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.contents())
+    }
+
+    pub fn contents(&self) -> String {
+        let mut text = String::new();
+        for (index, node) in self.lines.iter().enumerate() {
+            if index > 0 {
+                text.push_str(self.line_ending);
+            }
+            text.push_str(node.borrow().value.as_ref().expect("Node without a value"));
+        }
+        if self.trailing_newline && !self.lines.is_empty() {
+            text.push_str(self.line_ending);
+        }
+        text
+    }
+
+    pub fn line_count(&self) -> u32 {
+        self.lines.len()
+    }
+
+    pub fn line(&self, index: u32) -> Result<ElementRef<'_, String>, ListError> {
+        self.lines.get(index)
+    }
+
+    pub fn cursor(&self) -> u32 {
+        self.cursor
+    }
+
+    pub fn current_line(&self) -> Result<ElementRef<'_, String>, ListError> {
+        self.lines.get(self.cursor)
+    }
+
+    pub fn move_to(&mut self, line: u32) -> Result<(), ListError> {
+        if line >= self.lines.len() {
+            return Err(ListError::IndexOutOfBounds {
+                index: line,
+                len: self.lines.len(),
+            });
+        }
+        self.cursor = line;
+        Ok(())
+    }
+
+    pub fn move_up(&mut self) -> bool {
+        if self.cursor == 0 {
+            return false;
+        }
+        self.cursor -= 1;
+        true
+    }
+
+    pub fn move_down(&mut self) -> bool {
+        if self.cursor + 1 >= self.lines.len() {
+            return false;
+        }
+        self.cursor += 1;
+        true
+    }
+
+    // `line` may equal the line count to add a line at the end.
+    pub fn insert_line(&mut self, line: u32, text: &str) -> Result<(), EditError> {
+        self.perform(Edit::Insert {
+            line,
+            text: text.to_owned(),
+        })
+    }
+
+    pub fn delete_line(&mut self, line: u32) -> Result<(), EditError> {
+        self.perform(Edit::Delete { line })
+    }
+
+    // Moves everything from byte `column` onwards onto a new line below.
+    pub fn split_line(&mut self, line: u32, column: usize) -> Result<(), EditError> {
+        self.perform(Edit::Split { line, column })
+    }
+
+    // Appends the line below `line` to it.
+    pub fn join_lines(&mut self, line: u32) -> Result<(), EditError> {
+        self.perform(Edit::Join { line })
+    }
+
+    // Returns the line and byte column of the first match.
+    pub fn find(&self, pattern: &str) -> Option<(u32, usize)> {
+        self.find_all(pattern).into_iter().next()
+    }
+
+    pub fn find_all(&self, pattern: &str) -> Vec<(u32, usize)> {
+        let mut matches = Vec::new();
+        for (index, node) in self.lines.iter().enumerate() {
+            let node = node.borrow();
+            let text = node.value.as_ref().expect("Node without a value");
+            for (column, _) in text.match_indices(pattern) {
+                matches.push((index as u32, column));
+            }
+        }
+        matches
+    }
+
+    // Moves the cursor to the next line below it that contains `pattern`,
+    // wrapping around at the end of the buffer.
+    pub fn find_next(&mut self, pattern: &str) -> Option<(u32, usize)> {
+        let count = self.lines.len();
+        let found = self
+            .find_all(pattern)
+            .into_iter()
+            .min_by_key(|(line, _)| (line + count - self.cursor - 1) % count)?;
+
+        self.cursor = found.0;
+        Some(found)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    pub fn undo(&mut self) -> bool {
+        let Some(edit) = self.undo.pop() else {
+            return false;
+        };
+        let inverse = self.apply(edit).expect("Undo history out of sync");
+        self.redo.push(inverse);
+        true
+    }
+
+    pub fn redo(&mut self) -> bool {
+        let Some(edit) = self.redo.pop() else {
+            return false;
+        };
+        let inverse = self.apply(edit).expect("Redo history out of sync");
+        self.undo.push(inverse);
+        true
+    }
+
+    fn perform(&mut self, edit: Edit) -> Result<(), EditError> {
+        let inverse = self.apply(edit)?;
+        self.undo.push(inverse);
+        self.redo.clear();
+        Ok(())
+    }
+
+    // Applies `edit` and returns the edit that reverts it.
+    fn apply(&mut self, edit: Edit) -> Result<Edit, EditError> {
+        let inverse = match edit {
+            Edit::Insert { line, text } => {
+                self.lines.insert_many(line, iter::once(text))?;
+                Edit::Delete { line }
+            }
+            Edit::Delete { line } => {
+                let text = self.lines.pop_at(line)?;
+                Edit::Insert { line, text }
+            }
+            Edit::Split { line, column } => {
+                let rest = {
+                    let mut element = self.lines.get_mut(line)?;
+                    let mut text = element.borrow_mut();
+                    if !text.is_char_boundary(column) {
+                        return Err(EditError::InvalidColumn { line, column });
+                    }
+                    text.split_off(column)
+                };
+                self.lines.insert_many(line + 1, iter::once(rest))?;
+                Edit::Join { line }
+            }
+            Edit::Join { line } => {
+                self.lines.get(line)?;
+                let next = self.lines.pop_at(line + 1)?;
+
+                let mut element = self.lines.get_mut(line)?;
+                let mut text = element.borrow_mut();
+                let column = text.len();
+                text.push_str(&next);
+                Edit::Split { line, column }
+            }
+        };
+
+        self.cursor = self.cursor.min(self.lines.len().saturating_sub(1));
+        Ok(inverse)
+    }
+}
@@ -0,0 +1,481 @@
+use std::{
+    cell::{Ref, RefCell},
+    marker::PhantomData,
+    rc::{Rc, Weak},
+};
+
+use crate::conformance::Subject;
+use crate::deque::Deque;
+use crate::linked_list::ListError;
+
+// Same linking as `DoublyLinkedList` (strong `next`, weak `prev`), but each
+// node holds up to `N` consecutive elements inline, in an array inside the
+// node allocation. Walking the list then follows one link per node instead
+// of one per element, and the elements of a node sit next to each other in
+// memory.
+//
+// An insert into a full node splits it in two halves; a removal that leaves
+// a node less than half full merges it into a neighbour when they fit
+// together, so nodes stay reasonably packed.
+
+pub struct UnrolledNode<T, const N: usize> {
+    // The first `len` slots are `Some`, the rest are `None`.
+    values: [Option<T>; N],
+    len: usize,
+    prev: Option<Weak<RefCell<UnrolledNode<T, N>>>>,
+    next: Option<Rc<RefCell<UnrolledNode<T, N>>>>,
+}
+
+type Link<T, const N: usize> = Rc<RefCell<UnrolledNode<T, N>>>;
+
+pub struct UnrolledList<T, const N: usize = 16> {
+    count: u32,
+    head: Option<Link<T, N>>,
+    tail: Option<Weak<RefCell<UnrolledNode<T, N>>>>,
+}
+
+// One element of the list, borrowed out of its node on demand.
+pub struct UnrolledRef<'a, T, const N: usize> {
+    node: Link<T, N>,
+    offset: usize,
+    _list: PhantomData<&'a T>,
+}
+
+impl<T, const N: usize> UnrolledRef<'_, T, N> {
+    pub fn borrow(&self) -> Ref<'_, T> {
+        Ref::map(self.node.borrow(), |node| {
+            node.values[self.offset]
+                .as_ref()
+                .expect("Slot below len without a value")
+        })
+    }
+}
+
+// Yields the elements, front to back.
+pub struct Iter<'a, T, const N: usize> {
+    node: Option<Link<T, N>>,
+    offset: usize,
+    _list: PhantomData<&'a T>,
+}
+
+impl<'a, T, const N: usize> Iterator for Iter<'a, T, N> {
+    type Item = UnrolledRef<'a, T, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let node = self.node.clone()?;
+            if self.offset < node.borrow().len {
+                self.offset += 1;
+                return Some(UnrolledRef {
+                    node,
+                    offset: self.offset - 1,
+                    _list: PhantomData,
+                });
+            }
+            self.node = node.borrow().next.clone();
+            self.offset = 0;
+        }
+    }
+}
+
+// Yields whole nodes, from either end.
+pub struct Nodes<T, const N: usize> {
+    front: Option<Link<T, N>>,
+    back: Option<Link<T, N>>,
+}
+
+impl<T, const N: usize> Iterator for Nodes<T, N> {
+    type Item = Link<T, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let front = self.front.take()?;
+        if self
+            .back
+            .as_ref()
+            .is_some_and(|back| Rc::ptr_eq(back, &front))
+        {
+            self.back = None;
+        } else {
+            self.front = front.borrow().next.clone();
+        }
+        Some(front)
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for Nodes<T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let back = self.back.take()?;
+        if self
+            .front
+            .as_ref()
+            .is_some_and(|front| Rc::ptr_eq(front, &back))
+        {
+            self.front = None;
+        } else {
+            self.back = prev_of(&back);
+        }
+        Some(back)
+    }
+}
+
+impl<T, const N: usize> UnrolledNode<T, N> {
+    fn empty() -> Self {
+        UnrolledNode {
+            values: std::array::from_fn(|_| None),
+            len: 0,
+            prev: None,
+            next: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.values[..self.len].iter().flatten()
+    }
+
+    // Shifts the values from `offset` on one slot right to make room.
+    fn insert(&mut self, offset: usize, value: T) {
+        assert!(self.len < N && offset <= self.len);
+        self.values[offset..=self.len].rotate_right(1);
+        self.values[offset] = Some(value);
+        self.len += 1;
+    }
+
+    fn remove(&mut self, offset: usize) -> T {
+        assert!(offset < self.len);
+        let value = self.values[offset].take();
+        self.values[offset..self.len].rotate_left(1);
+        self.len -= 1;
+        value.expect("Slot below len without a value")
+    }
+
+    fn push(&mut self, value: T) {
+        self.insert(self.len, value);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.len.checked_sub(1).map(|last| self.remove(last))
+    }
+
+    // Moves the values from `at` on into a new, unlinked node.
+    fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.len);
+        let mut other = UnrolledNode::empty();
+        for (slot, value) in other.values.iter_mut().zip(&mut self.values[at..self.len]) {
+            *slot = value.take();
+        }
+        other.len = self.len - at;
+        self.len = at;
+        other
+    }
+
+    // Moves every value of `other` to the back of `self`.
+    fn append(&mut self, other: &mut Self) {
+        assert!(self.len + other.len <= N);
+        for (slot, value) in self.values[self.len..]
+            .iter_mut()
+            .zip(&mut other.values[..other.len])
+        {
+            *slot = value.take();
+        }
+        self.len += other.len;
+        other.len = 0;
+    }
+}
+
+impl<T, const N: usize> Default for UnrolledList<T, N> {
+    fn default() -> Self {
+        const { assert!(N >= 2, "nodes need room for at least two elements") };
+
+        UnrolledList {
+            count: 0,
+            head: None,
+            tail: None,
+        }
+    }
+}
+
+impl<T> UnrolledList<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T, const N: usize> UnrolledList<T, N> {
+    pub fn len(&self) -> u32 {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    pub fn node_capacity(&self) -> usize {
+        N
+    }
+
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        Iter {
+            node: self.head.clone(),
+            offset: 0,
+            _list: PhantomData,
+        }
+    }
+
+    pub fn nodes(&self) -> Nodes<T, N> {
+        Nodes {
+            front: self.head.clone(),
+            back: self.tail.as_ref().and_then(Weak::upgrade),
+        }
+    }
+
+    pub fn append(&mut self, value: T) {
+        let tail = self.tail.as_ref().and_then(Weak::upgrade);
+        match tail {
+            Some(tail) if tail.borrow().len < N => tail.borrow_mut().push(value),
+            tail => self.link_after(tail.as_ref(), new_node::<T, N>(value)),
+        }
+        self.count += 1;
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        match self.head.clone() {
+            Some(head) if head.borrow().len < N => head.borrow_mut().insert(0, value),
+            _ => self.link_after(None, new_node::<T, N>(value)),
+        }
+        self.count += 1;
+    }
+
+    pub fn pop_last(&mut self) -> Option<T> {
+        let tail = self.tail.as_ref().and_then(Weak::upgrade)?;
+        let value = tail.borrow_mut().pop();
+        self.count -= 1;
+        self.rebalance(&tail);
+        value
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.pop_at(0).ok()
+    }
+
+    pub fn insert_to(&mut self, index: u32, value: T) -> Result<(), ListError> {
+        let (mut node, mut offset) = self.locate(index)?;
+
+        if node.borrow().len == N {
+            let half = N / 2;
+            let second_half = node.borrow_mut().split_off(half);
+            let new_node = Rc::new(RefCell::new(second_half));
+            self.link_after(Some(&node), Rc::clone(&new_node));
+
+            if offset > half {
+                node = new_node;
+                offset -= half;
+            }
+        }
+
+        node.borrow_mut().insert(offset, value);
+        self.count += 1;
+        Ok(())
+    }
+
+    pub fn pop_at(&mut self, index: u32) -> Result<T, ListError> {
+        let (node, offset) = self.locate(index)?;
+        let value = node.borrow_mut().remove(offset);
+        self.count -= 1;
+        self.rebalance(&node);
+        Ok(value)
+    }
+
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut values = Vec::with_capacity(self.count as usize);
+        for node in self.nodes() {
+            values.extend(node.borrow().values().cloned());
+        }
+        values
+    }
+
+    // The node holding `index` and the position inside it, walking whole
+    // nodes from whichever end is closer.
+    fn locate(&self, index: u32) -> Result<(Link<T, N>, usize), ListError> {
+        if index >= self.count {
+            return Err(ListError::IndexOutOfBounds {
+                index,
+                len: self.count,
+            });
+        }
+
+        if index <= self.count / 2 {
+            let mut remaining = index as usize;
+            let mut cursor = self.head.clone();
+            while let Some(node) = cursor {
+                let len = node.borrow().len;
+                if remaining < len {
+                    return Ok((node, remaining));
+                }
+                remaining -= len;
+                cursor = node.borrow().next.clone();
+            }
+        } else {
+            let mut remaining = (self.count - 1 - index) as usize;
+            let mut cursor = self.tail.as_ref().and_then(Weak::upgrade);
+            while let Some(node) = cursor {
+                let len = node.borrow().len;
+                if remaining < len {
+                    return Ok((node, len - 1 - remaining));
+                }
+                remaining -= len;
+                cursor = prev_of(&node);
+            }
+        }
+        unreachable!("Node lengths don't add up to the list length")
+    }
+
+    // Drops `node` once it's empty, or merges it with a neighbour when it's
+    // under half full and the two fit in one node.
+    fn rebalance(&mut self, node: &Link<T, N>) {
+        let len = node.borrow().len;
+        if len == 0 {
+            self.unlink_node(node);
+            return;
+        }
+        if len >= N / 2 {
+            return;
+        }
+
+        let next = node.borrow().next.clone();
+        if let Some(next) = next.filter(|next| len + next.borrow().len <= N) {
+            node.borrow_mut().append(&mut next.borrow_mut());
+            self.unlink_node(&next);
+            return;
+        }
+
+        if let Some(prev) = prev_of(node).filter(|prev| prev.borrow().len + len <= N) {
+            prev.borrow_mut().append(&mut node.borrow_mut());
+            self.unlink_node(node);
+        }
+    }
+
+    // Links `node` right after `prev`, or at the front for `None`.
+    fn link_after(&mut self, prev: Option<&Link<T, N>>, node: Link<T, N>) {
+        let next = match prev {
+            Some(prev) => prev.borrow_mut().next.take(),
+            None => self.head.take(),
+        };
+
+        match &next {
+            Some(next) => next.borrow_mut().prev = Some(Rc::downgrade(&node)),
+            None => self.tail = Some(Rc::downgrade(&node)),
+        }
+        node.borrow_mut().prev = prev.map(Rc::downgrade);
+        node.borrow_mut().next = next;
+
+        match prev {
+            Some(prev) => prev.borrow_mut().next = Some(node),
+            None => self.head = Some(node),
+        }
+    }
+
+    fn unlink_node(&mut self, node: &Link<T, N>) {
+        let prev = prev_of(node);
+        let next = node.borrow_mut().next.take();
+        node.borrow_mut().prev = None;
+
+        match &next {
+            Some(next) => next.borrow_mut().prev = prev.as_ref().map(Rc::downgrade),
+            None => self.tail = prev.as_ref().map(Rc::downgrade),
+        }
+        match prev {
+            Some(prev) => prev.borrow_mut().next = next,
+            None => self.head = next,
+        }
+    }
+}
+
+impl<T, const N: usize> Drop for UnrolledList<T, N> {
+    // Same as `DoublyLinkedList`: unlink node by node instead of letting the
+    // chain drop recursively.
+    fn drop(&mut self) {
+        let mut cursor = self.head.take();
+        while let Some(node) = cursor {
+            cursor = node.borrow_mut().next.take();
+        }
+    }
+}
+
+impl<T, const N: usize> Deque for UnrolledList<T, N> {
+    type Item = T;
+
+    fn push_front(&mut self, value: T) {
+        UnrolledList::push_front(self, value);
+    }
+
+    fn push_back(&mut self, value: T) {
+        self.append(value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        UnrolledList::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        self.pop_last()
+    }
+
+    fn peek_front_with<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        let head = self.head.as_ref()?.borrow();
+        head.values[0].as_ref().map(f)
+    }
+
+    fn peek_back_with<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        let tail = self.tail.as_ref().and_then(Weak::upgrade)?;
+        let tail = tail.borrow();
+        let last = tail.len.checked_sub(1)?;
+        tail.values[last].as_ref().map(f)
+    }
+
+    fn len(&self) -> usize {
+        self.count as usize
+    }
+}
+
+impl<const N: usize> Subject for UnrolledList<u32, N> {
+    fn with_capacity(_capacity: usize) -> Self {
+        UnrolledList::default()
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        None
+    }
+
+    fn get(&self, index: usize) -> Option<u32> {
+        let (node, offset) = self.locate(u32::try_from(index).ok()?).ok()?;
+        let value = node.borrow().values[offset];
+        value
+    }
+}
+
+fn new_node<T, const N: usize>(value: T) -> Link<T, N> {
+    let mut node = UnrolledNode::empty();
+    node.push(value);
+    Rc::new(RefCell::new(node))
+}
+
+fn prev_of<T, const N: usize>(node: &Link<T, N>) -> Option<Link<T, N>> {
+    node.borrow().prev.as_ref().and_then(Weak::upgrade)
+}
@@ -0,0 +1,153 @@
+use std::rc::{Rc, Weak};
+
+use linked_list::linked_list::{DoublyLinkedList, ListError};
+
+fn list_of(values: &[u32]) -> DoublyLinkedList<u32> {
+    let mut list = DoublyLinkedList::new();
+    for &value in values {
+        list.append(value);
+    }
+    list
+}
+
+// Walks the `next` chain, checking the values and that every `prev` link
+// points back at the node before it; `last` goes through the tail.
+fn assert_list(list: &DoublyLinkedList<u32>, expected: &[u32]) {
+    let nodes: Vec<_> = list.iter().collect();
+    let values: Vec<u32> = nodes
+        .iter()
+        .map(|node| node.borrow().value.unwrap())
+        .collect();
+    assert_eq!(values, expected);
+    assert_eq!(list.len() as usize, expected.len());
+
+    for (index, node) in nodes.iter().enumerate() {
+        let prev = node.borrow().prev.as_ref().map(Weak::as_ptr);
+        let expected_prev = index
+            .checked_sub(1)
+            .map(|before| Rc::as_ptr(&nodes[before]));
+        assert_eq!(prev, expected_prev, "broken prev link at {}", index);
+    }
+    if let Some(&last) = expected.last() {
+        assert_eq!(*list.last().unwrap().borrow(), last);
+    }
+}
+
+fn out_of_bounds(index: u32, len: u32) -> ListError {
+    ListError::IndexOutOfBounds { index, len }
+}
+
+#[test]
+fn accessors_on_an_empty_list_report_bounds() {
+    let mut list = DoublyLinkedList::<u32>::new();
+
+    assert_eq!(list.get(0).err(), Some(out_of_bounds(0, 0)));
+    assert_eq!(list.get_mut(0).err(), Some(out_of_bounds(0, 0)));
+    assert_eq!(list.first().err(), Some(out_of_bounds(0, 0)));
+    assert_eq!(list.last().err(), Some(out_of_bounds(0, 0)));
+    assert_eq!(list.set(0, 1), Err(out_of_bounds(0, 0)));
+    assert_eq!(list.swap(0, 0), Err(out_of_bounds(0, 0)));
+}
+
+#[test]
+fn single_element_is_both_first_and_last() {
+    let mut list = list_of(&[7]);
+
+    assert_eq!(*list.first().unwrap().borrow(), 7);
+    assert_eq!(*list.last().unwrap().borrow(), 7);
+    assert_eq!(list.set(0, 8), Ok(7));
+    assert_eq!(list.swap(0, 0), Ok(()));
+    assert_eq!(list.get(1).err(), Some(out_of_bounds(1, 1)));
+    assert_list(&list, &[8]);
+}
+
+#[test]
+fn get_reaches_both_ends_and_the_middle() {
+    let list = list_of(&[0, 1, 2, 3, 4, 5]);
+
+    for index in 0..6 {
+        assert_eq!(*list.get(index).unwrap().borrow(), index);
+    }
+    assert_eq!(*list.last().unwrap().borrow(), 5);
+    assert_eq!(list.get(6).err(), Some(out_of_bounds(6, 6)));
+    assert_eq!(list.get(u32::MAX).err(), Some(out_of_bounds(u32::MAX, 6)));
+}
+
+#[test]
+fn get_mut_and_set_change_values_in_place() {
+    let mut list = list_of(&[1, 2, 3]);
+
+    *list.get_mut(2).unwrap().borrow_mut() += 10;
+    assert_eq!(list.set(0, 100), Ok(1));
+    assert_eq!(list.set(3, 0), Err(out_of_bounds(3, 3)));
+    assert_list(&list, &[100, 2, 13]);
+}
+
+#[test]
+fn swap_exchanges_values_without_relinking() {
+    let mut list = list_of(&[1, 2, 3, 4]);
+    let nodes: Vec<_> = list.iter().map(|node| Rc::as_ptr(&node)).collect();
+
+    assert_eq!(list.swap(0, 3), Ok(()));
+    assert_eq!(list.swap(1, 2), Ok(()));
+    assert_list(&list, &[4, 3, 2, 1]);
+    let swapped: Vec<_> = list.iter().map(|node| Rc::as_ptr(&node)).collect();
+    assert_eq!(swapped, nodes);
+
+    assert_eq!(list.swap(0, 4), Err(out_of_bounds(4, 4)));
+    assert_eq!(list.swap(9, 0), Err(out_of_bounds(9, 4)));
+    assert_list(&list, &[4, 3, 2, 1]);
+}
+
+// Regression: inserting at index 0 used to leave `count` unchanged.
+
+#[test]
+fn insert_at_the_front_counts_the_new_node() {
+    let mut list = list_of(&[1, 2]);
+
+    list.insert_to(0, 0).unwrap();
+    assert_list(&list, &[0, 1, 2]);
+}
+
+// Regression: a node inserted mid-list used to get no `prev` link, so
+// walking backwards skipped it.
+
+#[test]
+fn insert_in_the_middle_links_prev() {
+    let mut list = list_of(&[1, 3]);
+
+    list.insert_to(1, 2).unwrap();
+    assert_list(&list, &[1, 2, 3]);
+    assert_eq!(list.pop_last(), Some(3));
+    assert_eq!(list.pop_last(), Some(2));
+    assert_eq!(list.pop_last(), Some(1));
+    assert!(list.is_empty());
+}
+
+#[test]
+fn insert_to_rejects_the_end_and_empty_lists() {
+    let mut list = DoublyLinkedList::new();
+    assert_eq!(list.insert_to(0, 1), Err(out_of_bounds(0, 0)));
+
+    list.append(1);
+    assert_eq!(list.insert_to(1, 2), Err(out_of_bounds(1, 1)));
+    assert_list(&list, &[1]);
+}
+
+#[test]
+fn pop_at_unlinks_from_either_end_and_the_middle() {
+    let mut list = list_of(&[0, 1, 2, 3, 4]);
+
+    assert_eq!(list.pop_at(2), Ok(2));
+    assert_list(&list, &[0, 1, 3, 4]);
+    assert_eq!(list.pop_at(0), Ok(0));
+    assert_list(&list, &[1, 3, 4]);
+    assert_eq!(list.pop_at(2), Ok(4));
+    assert_list(&list, &[1, 3]);
+    assert_eq!(list.pop_at(2), Err(out_of_bounds(2, 2)));
+
+    assert_eq!(list.pop_at(1), Ok(3));
+    assert_eq!(list.pop_at(0), Ok(1));
+    assert_list(&list, &[]);
+    assert_eq!(list.pop_at(0), Err(out_of_bounds(0, 0)));
+}
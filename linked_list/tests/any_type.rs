@@ -0,0 +1,181 @@
+use std::{
+    cell::Cell,
+    fmt::Debug,
+    panic::{self, AssertUnwindSafe},
+    rc::Rc,
+};
+
+use linked_list::linked_list::DoublyLinkedList;
+
+// Neither `Display` nor `Clone`.
+#[derive(Debug, PartialEq)]
+struct Opaque(u32);
+
+struct DropCounter {
+    drops: Rc<Cell<u32>>,
+}
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+    }
+}
+
+struct PanicOnDrop {
+    drops: Rc<Cell<u32>>,
+    panics: bool,
+}
+
+impl Drop for PanicOnDrop {
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+        if self.panics {
+            panic!("PanicOnDrop");
+        }
+    }
+}
+
+fn counters(drops: &Rc<Cell<u32>>, n: u32) -> DoublyLinkedList<DropCounter> {
+    let mut list = DoublyLinkedList::new();
+    for _ in 0..n {
+        list.append(DropCounter {
+            drops: Rc::clone(drops),
+        });
+    }
+    list
+}
+
+fn assert_links<T: Debug + PartialEq>(list: &DoublyLinkedList<T>, expected: &[T]) {
+    assert_eq!(list.len() as usize, expected.len());
+
+    let forward = list.iter().count();
+    assert_eq!(forward, expected.len());
+
+    for (index, value) in expected.iter().enumerate() {
+        assert_eq!(&*list.get(index as u32).unwrap().borrow(), value);
+    }
+    assert_eq!(
+        list.rposition(|_| true),
+        expected.len().checked_sub(1).map(|i| i as u32)
+    );
+}
+
+#[test]
+fn core_operations_work_without_display_or_clone() {
+    let mut list = DoublyLinkedList::new();
+
+    list.append(Opaque(1));
+    list.append(Opaque(3));
+    list.insert_to(1, Opaque(2)).unwrap();
+    list.insert_to(0, Opaque(0)).unwrap();
+    assert_links(&list, &[Opaque(0), Opaque(1), Opaque(2), Opaque(3)]);
+
+    assert_eq!(list.pop_at(1).unwrap(), Opaque(1));
+    assert_eq!(list.pop_last(), Some(Opaque(3)));
+    assert_eq!(list.set(0, Opaque(7)).unwrap(), Opaque(0));
+    assert_links(&list, &[Opaque(7), Opaque(2)]);
+
+    assert_eq!(list.pop_at(0).unwrap(), Opaque(7));
+    assert_eq!(list.pop_last(), Some(Opaque(2)));
+    assert_eq!(list.pop_last(), None);
+    assert!(list.is_empty());
+}
+
+#[test]
+fn stores_trait_objects() {
+    let mut list: DoublyLinkedList<Box<dyn Fn(u32) -> u32>> = DoublyLinkedList::new();
+
+    list.append(Box::new(|x| x + 1));
+    list.append(Box::new(|x| x * 10));
+    list.insert_to(0, Box::new(|x| x - 1)).unwrap();
+
+    let result = list
+        .iter()
+        .fold(5, |acc, node| (node.borrow().value.as_ref().unwrap())(acc));
+    assert_eq!(result, 50);
+
+    let first = list.pop_at(0).unwrap();
+    assert_eq!(first(5), 4);
+}
+
+#[test]
+fn zero_sized_elements() {
+    let mut list = DoublyLinkedList::new();
+
+    for _ in 0..1000 {
+        list.append(());
+    }
+    list.insert_to(500, ()).unwrap();
+    assert_eq!(list.len(), 1001);
+    assert_eq!(list.drain(100..200).unwrap().count(), 100);
+    assert_eq!(list.pop_at(0), Ok(()));
+    assert_eq!(list.pop_last(), Some(()));
+    assert_eq!(list.len(), 899);
+    assert_eq!(list.iter().count(), 899);
+}
+
+#[test]
+fn every_element_is_dropped_once() {
+    let drops = Rc::new(Cell::new(0));
+    let mut list = counters(&drops, 10);
+
+    drop(list.pop_at(3).unwrap());
+    drop(list.pop_last());
+    assert_eq!(drops.get(), 2);
+
+    let mut drain = list.drain(2..6).unwrap();
+    drop(drain.next());
+    drop(drain);
+    assert_eq!(drops.get(), 6);
+
+    drop(list);
+    assert_eq!(drops.get(), 10);
+}
+
+#[test]
+fn dropping_a_long_list_does_not_overflow_the_stack() {
+    let drops = Rc::new(Cell::new(0));
+    let list = counters(&drops, 200_000);
+
+    drop(list);
+    assert_eq!(drops.get(), 200_000);
+}
+
+#[test]
+fn list_stays_consistent_when_an_element_panics_in_drop() {
+    let drops = Rc::new(Cell::new(0));
+    let mut list = DoublyLinkedList::new();
+    for i in 0..4 {
+        list.append(PanicOnDrop {
+            drops: Rc::clone(&drops),
+            panics: i == 1,
+        });
+    }
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| drop(list.pop_at(1))));
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 1);
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.iter().count(), 3);
+
+    drop(list.pop_last());
+    assert_eq!(drops.get(), 2);
+    drop(list);
+    assert_eq!(drops.get(), 4);
+}
+
+#[test]
+fn remaining_elements_are_dropped_when_one_panics_during_list_drop() {
+    let drops = Rc::new(Cell::new(0));
+    let mut list = DoublyLinkedList::new();
+    for i in 0..5 {
+        list.append(PanicOnDrop {
+            drops: Rc::clone(&drops),
+            panics: i == 2,
+        });
+    }
+
+    let result = panic::catch_unwind(AssertUnwindSafe(move || drop(list)));
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 5);
+}
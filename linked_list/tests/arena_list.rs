@@ -0,0 +1,283 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use linked_list::arena_list::{ArenaList, HandleError};
+use linked_list::conformance;
+use linked_list::linked_list::ListError;
+use linked_list::random::XorShift;
+
+// Checks the values and that the `prev` links walk back over the same
+// elements as the `next` links.
+fn assert_list(list: &ArenaList<u32>, expected: &[u32]) {
+    assert_eq!(list.to_vec(), expected);
+    assert_eq!(list.len() as usize, expected.len());
+    let mut backwards: Vec<u32> = list.iter().rev().copied().collect();
+    backwards.reverse();
+    assert_eq!(backwards, expected);
+}
+
+#[test]
+fn conforms_to_vec_deque() {
+    conformance::check_all::<ArenaList<u32>>();
+}
+
+#[test]
+fn index_api_matches_doubly_linked_list() {
+    let mut list = ArenaList::new();
+    for value in [1, 2, 4, 5] {
+        list.append(value);
+    }
+    list.insert_to(2, 3).unwrap();
+    list.insert_to(0, 0).unwrap();
+    assert_eq!(list.to_vec(), vec![0, 1, 2, 3, 4, 5]);
+
+    assert_eq!(list.pop_at(5), Ok(5));
+    assert_eq!(list.pop_at(0), Ok(0));
+    assert_eq!(
+        list.pop_at(4),
+        Err(ListError::IndexOutOfBounds { index: 4, len: 4 })
+    );
+    assert!(list.insert_to(4, 9).is_err());
+
+    assert_eq!(list.set(0, 10), Ok(1));
+    list.swap(0, 3).unwrap();
+    assert_eq!(list.to_vec(), vec![4, 2, 3, 10]);
+    *list.get_mut(1).unwrap() += 20;
+    assert_eq!(list.get(1), Ok(&22));
+    assert_eq!(list.first(), Ok(&4));
+    assert_eq!(list.last(), Ok(&10));
+    assert!(list.contains(&3));
+    assert_eq!(list.position(|&value| value > 20), Some(1));
+
+    let backwards: Vec<_> = list.iter().rev().copied().collect();
+    assert_eq!(backwards, vec![10, 3, 22, 4]);
+}
+
+#[test]
+fn handles_survive_other_changes() {
+    let mut list = ArenaList::new();
+    let a = list.append("a");
+    let c = list.append("c");
+    let b = list.insert_before(c, "b").unwrap();
+    list.push_front("start");
+    list.insert_after(c, "d").unwrap();
+
+    assert_eq!(list.to_vec(), vec!["start", "a", "b", "c", "d"]);
+    assert_eq!(list.pop_front(), Some("start"));
+    assert_eq!(list.get_by_id(b), Ok(&"b"));
+    assert_eq!(list.next_id(a), Ok(Some(b)));
+    assert_eq!(list.prev_id(a), Ok(None));
+    assert_eq!(list.front_id(), Some(a));
+
+    *list.get_by_id_mut(c).unwrap() = "C";
+    assert_eq!(list.remove(b), Ok("b"));
+    assert_eq!(list.to_vec(), vec!["a", "C", "d"]);
+    assert_eq!(list.next_id(a), Ok(Some(c)));
+}
+
+#[test]
+fn stale_handles_are_detected() {
+    let mut list = ArenaList::new();
+    let first = list.append(1);
+    list.append(2);
+
+    assert_eq!(list.remove(first), Ok(1));
+    assert!(!list.is_valid(first));
+    assert_eq!(list.get_by_id(first), Err(HandleError::Stale(first)));
+    assert_eq!(list.remove(first), Err(HandleError::Stale(first)));
+    assert!(list.insert_after(first, 5).is_err());
+
+    // The freed slot is reused, but the old handle still doesn't match.
+    let reused = list.push_front(3);
+    assert_ne!(reused, first);
+    assert!(list.get_by_id(first).is_err());
+    assert_eq!(list.get_by_id(reused), Ok(&3));
+    assert_eq!(list.to_vec(), vec![3, 2]);
+}
+
+#[test]
+fn ids_from_another_list_are_rejected() {
+    let mut list = ArenaList::new();
+    let mut other = ArenaList::new();
+    let mine = list.append(1);
+    let theirs = other.append(2);
+
+    // Same slot and generation, but handed out by `other`.
+    assert!(!list.is_valid(theirs));
+    assert_eq!(list.get_by_id(theirs), Err(HandleError::Foreign(theirs)));
+    assert_eq!(list.remove(theirs), Err(HandleError::Foreign(theirs)));
+    assert_eq!(
+        list.insert_after(theirs, 3),
+        Err(HandleError::Foreign(theirs))
+    );
+    assert_eq!(
+        HandleError::Foreign(theirs).to_string(),
+        "node 0 belongs to another list"
+    );
+
+    assert_eq!(list.to_vec(), vec![1]);
+    assert_eq!(other.get_by_id(theirs), Ok(&2));
+    assert_eq!(list.get_by_id(mine), Ok(&1));
+}
+
+#[test]
+fn freed_slots_are_reused() {
+    let mut list = ArenaList::with_capacity(8);
+    for value in 0..8 {
+        list.append(value);
+    }
+    let allocated = list.memory_usage().allocated;
+
+    for round in 0..100 {
+        assert_eq!(list.pop_front(), Some(round));
+        list.append(round + 8);
+    }
+    assert_eq!(list.memory_usage().allocated, allocated);
+    assert_eq!(list.to_vec(), (100..108).collect::<Vec<_>>());
+}
+
+#[test]
+fn splice_replaces_a_range_and_keeps_other_handles() {
+    let mut list: ArenaList<u32> = (0..6).collect();
+    let first = list.front_id().unwrap();
+    let last = list.back_id().unwrap();
+    let cut = list.node_id(2).unwrap();
+
+    assert_eq!(list.splice(2..4, [20, 21, 22]), Ok(vec![2, 3]));
+    assert_list(&list, &[0, 1, 20, 21, 22, 4, 5]);
+    assert_eq!(list.get_by_id(first), Ok(&0));
+    assert_eq!(list.get_by_id(last), Ok(&5));
+    assert_eq!(list.get_by_id(cut), Err(HandleError::Stale(cut)));
+
+    assert_eq!(list.splice(.., [9]), Ok(vec![0, 1, 20, 21, 22, 4, 5]));
+    assert_list(&list, &[9]);
+
+    list.insert_many(1, [10, 11]).unwrap();
+    list.insert_many(0, [8]).unwrap();
+    assert_list(&list, &[8, 9, 10, 11]);
+    assert_eq!(
+        list.insert_many(5, [1]),
+        Err(ListError::InvalidRange {
+            start: 5,
+            end: 5,
+            len: 4
+        })
+    );
+    assert!(list.splice(2..=4, []).is_err());
+}
+
+#[test]
+fn splice_leaves_the_list_alone_if_the_replacement_panics() {
+    let mut list: ArenaList<u32> = (0..4).collect();
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let replacement = (0..3).inspect(|&value| assert!(value < 2, "replacement failed"));
+        list.splice(1..3, replacement)
+    }));
+
+    assert!(result.is_err());
+    assert_list(&list, &[0, 1, 2, 3]);
+}
+
+#[test]
+fn drain_and_remove_range_take_values_out() {
+    let mut list: ArenaList<u32> = (0..8).collect();
+    let kept = list.node_id(6).unwrap();
+
+    let mut drain = list.drain(1..5).unwrap();
+    assert_eq!(drain.len(), 4);
+    assert_eq!(drain.next(), Some(1));
+    assert_eq!(drain.next_back(), Some(4));
+    drop(drain);
+    assert_list(&list, &[0, 5, 6, 7]);
+    assert_eq!(list.get_by_id(kept), Ok(&6));
+
+    let removed = list.remove_range(2..).unwrap();
+    assert_eq!(removed.to_vec(), vec![6, 7]);
+    assert!(!list.is_valid(kept));
+    assert_list(&list, &[0, 5]);
+
+    assert!(list.remove_range(1..1).unwrap().is_empty());
+    assert!(list.drain(..=2).is_err());
+    assert_eq!(list.into_iter().rev().collect::<Vec<_>>(), vec![5, 0]);
+}
+
+#[test]
+fn search_and_remove_by_value() {
+    let mut list: ArenaList<u32> = [1, 2, 3, 2, 1, 2].into_iter().collect();
+
+    assert_eq!(list.rposition(|&value| value == 1), Some(4));
+    assert_eq!(list.rposition(|&value| value > 5), None);
+    assert_eq!(
+        list.find_map(|&value| (value > 2).then_some(value * 10)),
+        Some(30)
+    );
+
+    assert_eq!(list.remove_first(&2), Some(2));
+    assert_list(&list, &[1, 3, 2, 1, 2]);
+    assert_eq!(list.remove_all(&2), 2);
+    assert_list(&list, &[1, 3, 1]);
+    assert_eq!(list.remove_first(&7), None);
+    assert_eq!(list.remove_all(&7), 0);
+}
+
+#[test]
+fn rotation_relinks_without_moving_values() {
+    let mut list: ArenaList<u32> = (0..5).collect();
+    let ids: Vec<_> = (0..5).map(|index| list.node_id(index).unwrap()).collect();
+
+    list.rotate_left(2);
+    assert_list(&list, &[2, 3, 4, 0, 1]);
+    list.rotate_right(7);
+    assert_list(&list, &[0, 1, 2, 3, 4]);
+    list.rotate_left(5);
+    assert_list(&list, &[0, 1, 2, 3, 4]);
+
+    list.rotate_right(1);
+    assert_list(&list, &[4, 0, 1, 2, 3]);
+    for (value, id) in (0..5).zip(&ids) {
+        assert_eq!(list.get_by_id(*id), Ok(&value));
+    }
+
+    let mut empty = ArenaList::<u32>::new();
+    empty.rotate_left(3);
+    empty.rotate_right(3);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn shuffle_keeps_handles_on_their_values() {
+    let mut list: ArenaList<u32> = (0..50).collect();
+    let ids: Vec<_> = (0..50).map(|index| list.node_id(index).unwrap()).collect();
+    let mut rng = XorShift::new(7);
+
+    list.shuffle(&mut rng);
+    let mut values = list.to_vec();
+    assert_ne!(values, (0..50).collect::<Vec<_>>());
+    assert_list(&list, &values);
+    values.sort_unstable();
+    assert_eq!(values, (0..50).collect::<Vec<_>>());
+    for (value, id) in (0..50).zip(&ids) {
+        assert_eq!(list.get_by_id(*id), Ok(&value));
+    }
+
+    assert!(list.contains(list.choose(&mut rng).unwrap()));
+    let mut sample: Vec<u32> = list.sample(10, &mut rng).into_iter().copied().collect();
+    sample.sort_unstable();
+    sample.dedup();
+    assert_eq!(sample.len(), 10);
+    assert_eq!(list.sample(80, &mut rng).len(), 50);
+    assert_eq!(ArenaList::<u32>::new().choose(&mut rng), None);
+}
+
+#[test]
+fn set_operations_on_sorted_lists() {
+    let a = || -> ArenaList<u32> { [1, 3, 4, 6, 8].into_iter().collect() };
+    let b = || -> ArenaList<u32> { [2, 3, 6, 7].into_iter().collect() };
+
+    assert_list(&a().merge(b(), u32::cmp), &[1, 2, 3, 3, 4, 6, 6, 7, 8]);
+    assert_list(&a().union(b()), &[1, 2, 3, 4, 6, 7, 8]);
+    assert_list(&a().intersection(b()), &[3, 6]);
+    assert_list(&a().difference(b()), &[1, 4, 8]);
+    assert_list(&ArenaList::new().union(b()), &[2, 3, 6, 7]);
+    assert_list(&a().difference(ArenaList::new()), &[1, 3, 4, 6, 8]);
+}
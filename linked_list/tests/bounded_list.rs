@@ -0,0 +1,94 @@
+use std::{cell::RefCell, rc::Rc};
+
+use linked_list::bounded_list::{BoundedList, EvictionPolicy, PushError};
+
+fn contents(list: &BoundedList<String>) -> Vec<String> {
+    list.list()
+        .iter()
+        .map(|node| node.borrow().value.clone().unwrap())
+        .collect()
+}
+
+#[test]
+fn drop_oldest_returns_evicted_elements() {
+    let mut list = BoundedList::new(EvictionPolicy::DropOldest).max_len(2);
+
+    assert_eq!(list.push(1), Ok(vec![]));
+    assert_eq!(list.push(2), Ok(vec![]));
+    assert_eq!(list.push(3), Ok(vec![1]));
+    assert_eq!(list.len(), 2);
+    assert_eq!(list.pop_first(), Some(2));
+}
+
+#[test]
+fn drop_newest_evicts_from_the_back() {
+    let mut list = BoundedList::new(EvictionPolicy::DropNewest).max_len(2);
+
+    list.push(1).unwrap();
+    list.push(2).unwrap();
+    assert_eq!(list.push(3), Ok(vec![2]));
+    assert_eq!(list.pop_last(), Some(3));
+    assert_eq!(list.pop_last(), Some(1));
+}
+
+#[test]
+fn reject_hands_the_value_back() {
+    let mut list = BoundedList::new(EvictionPolicy::Reject).max_len(1);
+
+    list.push(1).unwrap();
+    assert_eq!(list.push(2), Err(PushError::Rejected(2)));
+    assert_eq!(list.len(), 1);
+}
+
+#[test]
+fn weight_budget_evicts_until_the_value_fits() {
+    let mut list =
+        BoundedList::new(EvictionPolicy::DropOldest).max_weight(10, |s: &String| s.len());
+
+    list.push("aaaa".to_owned()).unwrap();
+    list.push("bbb".to_owned()).unwrap();
+    list.push("cc".to_owned()).unwrap();
+    assert_eq!(list.weight(), 9);
+
+    let evicted = list.push("ddddddd".to_owned()).unwrap();
+    assert_eq!(evicted, vec!["aaaa".to_owned(), "bbb".to_owned()]);
+    assert_eq!(contents(&list), vec!["cc", "ddddddd"]);
+    assert_eq!(list.weight(), 9);
+
+    let too_heavy = list.push("x".repeat(11));
+    assert_eq!(
+        too_heavy.map_err(PushError::into_inner),
+        Err("x".repeat(11))
+    );
+    assert_eq!(list.weight(), 9);
+}
+
+#[test]
+fn weight_budget_set_after_pushes_counts_existing_elements() {
+    let mut list = BoundedList::new(EvictionPolicy::DropOldest);
+    list.push(5).unwrap();
+
+    let mut list = list.max_weight(10, |n: &usize| *n);
+    assert_eq!(list.weight(), 5);
+
+    assert_eq!(list.push(8), Ok(vec![5]));
+    assert_eq!(list.weight(), 8);
+    assert_eq!(list.pop_first(), Some(8));
+}
+
+#[test]
+fn callback_receives_evicted_elements() {
+    let evicted = Rc::new(RefCell::new(Vec::new()));
+    let sink = Rc::clone(&evicted);
+    let mut list = BoundedList::new(EvictionPolicy::DropOldest)
+        .max_len(3)
+        .max_weight(100, |n: &usize| *n)
+        .on_evict(move |n| sink.borrow_mut().push(n));
+
+    for n in [10, 20, 30, 40, 90] {
+        assert_eq!(list.push(n), Ok(vec![]));
+    }
+    assert_eq!(*evicted.borrow(), vec![10, 20, 30, 40]);
+    assert_eq!(list.len(), 1);
+    assert_eq!(list.weight(), 90);
+}
@@ -0,0 +1,123 @@
+use std::io::Cursor;
+
+use linked_list::linked_list::{CodecError, DoublyLinkedList, ListCodec};
+
+fn list_of<T>(values: Vec<T>) -> DoublyLinkedList<T> {
+    let mut list = DoublyLinkedList::new();
+    for value in values {
+        list.append(value);
+    }
+    list
+}
+
+fn values<T: Clone>(list: &DoublyLinkedList<T>) -> Vec<T> {
+    list.iter()
+        .map(|node| node.borrow().value.clone().unwrap())
+        .collect()
+}
+
+fn binary_round_trip<T: ListCodec + Clone>(list: &DoublyLinkedList<T>) -> DoublyLinkedList<T> {
+    let mut bytes = Vec::new();
+    list.write_to(&mut bytes).unwrap();
+    DoublyLinkedList::read_from(Cursor::new(bytes)).unwrap()
+}
+
+#[test]
+fn binary_round_trip_for_every_codec() {
+    let ints = list_of(vec![i64::MIN, -1, 0, 1, i64::MAX]);
+    assert_eq!(values(&binary_round_trip(&ints)), values(&ints));
+
+    let small = list_of(vec![0u8, 7, 255]);
+    assert_eq!(values(&binary_round_trip(&small)), values(&small));
+
+    let sizes = list_of(vec![0usize, usize::MAX]);
+    assert_eq!(values(&binary_round_trip(&sizes)), values(&sizes));
+
+    let strings = list_of(vec![String::new(), "héllo".to_owned(), "a\"b\n".to_owned()]);
+    assert_eq!(values(&binary_round_trip(&strings)), values(&strings));
+
+    let bytes = list_of(vec![vec![], vec![0u8, 1, 2], vec![255; 300]]);
+    assert_eq!(values(&binary_round_trip(&bytes)), values(&bytes));
+
+    let empty: DoublyLinkedList<u32> = DoublyLinkedList::new();
+    assert!(binary_round_trip(&empty).is_empty());
+}
+
+#[test]
+fn binary_format_is_versioned_and_length_prefixed() {
+    let mut bytes = Vec::new();
+    list_of(vec![1u16, 2]).write_to(&mut bytes).unwrap();
+    assert_eq!(bytes, b"DLL\x01\x02\x00\x00\x00\x01\x00\x02\x00");
+
+    let mut bytes = Vec::new();
+    list_of(vec!["ab".to_owned()]).write_to(&mut bytes).unwrap();
+    assert_eq!(bytes, b"DLL\x01\x01\x00\x00\x00\x02\x00\x00\x00ab");
+}
+
+#[test]
+fn binary_rejects_bad_input() {
+    let read = |bytes: &[u8]| DoublyLinkedList::<String>::read_from(Cursor::new(bytes.to_vec()));
+
+    assert!(matches!(
+        read(b"XYZ\x01\x00\x00\x00\x00"),
+        Err(CodecError::InvalidHeader)
+    ));
+    assert!(matches!(
+        read(b"DLL\x09\x00\x00\x00\x00"),
+        Err(CodecError::UnsupportedVersion(9))
+    ));
+    assert!(matches!(
+        read(b"DLL\x01\x02\x00\x00\x00"),
+        Err(CodecError::Io(_))
+    ));
+    assert!(matches!(
+        read(b"DLL\x01\x01\x00\x00\x00\xff\xff\xff\xffab"),
+        Err(CodecError::Io(_))
+    ));
+    assert!(matches!(
+        read(b"DLL\x01\x01\x00\x00\x00\x01\x00\x00\x00\xff"),
+        Err(CodecError::InvalidUtf8)
+    ));
+}
+
+#[test]
+fn text_round_trip_for_every_codec() {
+    let ints = list_of(vec![1, -2, 3]);
+    assert_eq!(ints.to_text(), "[1, -2, 3]");
+    assert_eq!(
+        values(&DoublyLinkedList::<i32>::from_text(&ints.to_text()).unwrap()),
+        vec![1, -2, 3]
+    );
+
+    let strings = list_of(vec!["a, b".to_owned(), "q\"\\\t".to_owned(), String::new()]);
+    assert_eq!(strings.to_text(), r#"["a, b", "q\"\\\t", ""]"#);
+    let parsed = DoublyLinkedList::<String>::from_text(&strings.to_text()).unwrap();
+    assert_eq!(values(&parsed), values(&strings));
+
+    let bytes = list_of(vec![vec![1u8, 2], vec![]]);
+    assert_eq!(bytes.to_text(), "[[1, 2], []]");
+    let parsed = DoublyLinkedList::<Vec<u8>>::from_text(&bytes.to_text()).unwrap();
+    assert_eq!(values(&parsed), values(&bytes));
+
+    let empty: DoublyLinkedList<u8> = DoublyLinkedList::new();
+    assert_eq!(empty.to_text(), "[]");
+}
+
+#[test]
+fn text_parser_accepts_whitespace_and_rejects_garbage() {
+    let parsed = DoublyLinkedList::<u32>::from_text("  [ 1 ,2,\n 3 ]  ").unwrap();
+    assert_eq!(values(&parsed), vec![1, 2, 3]);
+
+    for bad in ["1, 2", "[1, 2", "[1 2]", "[1,]", "[1] x", "[-1]"] {
+        let result = DoublyLinkedList::<u32>::from_text(bad);
+        assert!(matches!(result, Err(CodecError::Parse(_))), "{:?}", bad);
+    }
+    assert!(matches!(
+        DoublyLinkedList::<u8>::from_text("[256]"),
+        Err(CodecError::Parse(_))
+    ));
+    assert!(matches!(
+        DoublyLinkedList::<String>::from_text("[\"a]"),
+        Err(CodecError::Parse(_))
+    ));
+}
@@ -0,0 +1,15 @@
+use std::collections::VecDeque;
+
+use linked_list::conformance;
+use linked_list::linked_list::DoublyLinkedList;
+
+// The reference model against itself, to keep the suite honest.
+#[test]
+fn vec_deque_conforms() {
+    conformance::check_all::<VecDeque<u32>>();
+}
+
+#[test]
+fn rc_list_conforms() {
+    conformance::check_all::<DoublyLinkedList<u32>>();
+}
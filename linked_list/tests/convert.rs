@@ -0,0 +1,77 @@
+use std::collections::VecDeque;
+
+use linked_list::linked_list::{DoublyLinkedList, SyncDoublyLinkedList};
+use linked_list::sentinel_list::SentinelList;
+
+// Deliberately neither `Clone` nor `Copy`: conversions have to move values.
+#[derive(Debug, PartialEq)]
+struct Token(u32);
+
+fn tokens(values: &[u32]) -> Vec<Token> {
+    values.iter().map(|&value| Token(value)).collect()
+}
+
+#[test]
+fn vec_round_trip_moves_values() {
+    let list = DoublyLinkedList::from(tokens(&[1, 2, 3]));
+    assert_eq!(list.len(), 3);
+    assert_eq!(Vec::from(list), tokens(&[1, 2, 3]));
+
+    let empty: DoublyLinkedList<Token> = Vec::new().into();
+    assert!(empty.is_empty());
+    assert_eq!(Vec::from(empty), Vec::new());
+}
+
+#[test]
+fn vec_deque_round_trip() {
+    let deque: VecDeque<_> = tokens(&[4, 5, 6]).into();
+    let list = DoublyLinkedList::from(deque);
+    let back: VecDeque<Token> = list.into();
+    assert_eq!(back, VecDeque::from(tokens(&[4, 5, 6])));
+}
+
+#[test]
+fn to_vec_leaves_the_list_intact() {
+    let list: DoublyLinkedList<String> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+    assert_eq!(list.to_vec(), vec!["a", "b"]);
+    assert_eq!(list.len(), 2);
+}
+
+#[test]
+fn into_iter_runs_from_both_ends() {
+    let mut values = DoublyLinkedList::from(tokens(&[1, 2, 3, 4])).into_iter();
+    assert_eq!(values.len(), 4);
+    assert_eq!(values.next(), Some(Token(1)));
+    assert_eq!(values.next_back(), Some(Token(4)));
+    assert_eq!(values.len(), 2);
+    assert_eq!(values.collect::<Vec<_>>(), tokens(&[2, 3]));
+}
+
+#[test]
+fn extend_appends_at_the_back() {
+    let mut list = DoublyLinkedList::from(vec![1, 2]);
+    list.extend(vec![3, 4]);
+    assert_eq!(list.to_vec(), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn sync_list_converts_like_the_rc_list() {
+    let list: SyncDoublyLinkedList<_> = tokens(&[7, 8]).into_iter().collect();
+    assert_eq!(list.len(), 2);
+    assert_eq!(Vec::from(list), tokens(&[7, 8]));
+}
+
+#[test]
+fn sentinel_list_converts_both_ways() {
+    let sentinel = SentinelList::from(tokens(&[1, 2, 3]));
+    let list = DoublyLinkedList::from(sentinel);
+    assert_eq!(list.len(), 3);
+
+    let sentinel = SentinelList::from(list);
+    assert_eq!(sentinel.len(), 3);
+    assert_eq!(Vec::from(sentinel), tokens(&[1, 2, 3]));
+
+    let sentinel: SentinelList<_> = VecDeque::from(vec![1, 2]).into();
+    assert_eq!(sentinel.to_vec(), vec![1, 2]);
+    assert_eq!(VecDeque::from(sentinel), VecDeque::from(vec![1, 2]));
+}
@@ -0,0 +1,137 @@
+use linked_list::cow_list::CowList;
+use linked_list::linked_list::{DoublyLinkedList, ListError};
+
+fn shared(values: &[u32]) -> CowList<u32> {
+    DoublyLinkedList::from(values.to_vec()).into()
+}
+
+#[test]
+fn clones_share_until_written() {
+    let original = shared(&[1, 2, 3]);
+    let reader = original.clone();
+    let other_reader = original.clone();
+
+    assert_eq!(original.share_count(), 3);
+    assert!(reader.shares_with(&original));
+    assert_eq!(reader.to_vec(), vec![1, 2, 3]);
+    assert_eq!(*other_reader.get(1).unwrap().borrow(), 2);
+    assert!(other_reader.contains(&3));
+}
+
+#[test]
+fn readers_never_see_a_writers_changes() {
+    let original = shared(&[1, 2, 3]);
+    let mut writer = original.clone();
+    let reader = original.clone();
+
+    writer.append(4);
+    writer.set(0, 10).unwrap();
+    assert_eq!(writer.pop_front(), Some(10));
+
+    assert_eq!(writer.to_vec(), vec![2, 3, 4]);
+    assert_eq!(original.to_vec(), vec![1, 2, 3]);
+    assert_eq!(reader.to_vec(), vec![1, 2, 3]);
+
+    assert!(writer.is_unique());
+    assert!(!writer.shares_with(&original));
+    assert_eq!(original.share_count(), 2);
+}
+
+#[test]
+fn original_holder_writing_does_not_leak_to_clones() {
+    let mut original = shared(&[5, 6]);
+    let snapshot = original.clone();
+
+    original.push_front(4);
+    original.insert_to(1, 9).unwrap();
+
+    assert_eq!(original.to_vec(), vec![4, 9, 5, 6]);
+    assert_eq!(snapshot.to_vec(), vec![5, 6]);
+    assert!(snapshot.is_unique());
+}
+
+#[test]
+fn reads_hand_out_values_not_shared_nodes() {
+    let original = shared(&[1, 2, 3]);
+    let mut writer = original.clone();
+
+    let values: Vec<u32> = original.iter().map(|value| *value.borrow()).collect();
+    assert_eq!(values, vec![1, 2, 3]);
+    assert_eq!(*original.first().unwrap().borrow(), 1);
+    assert_eq!(*original.last().unwrap().borrow(), 3);
+    assert_eq!(original.position(|&value| value == 2), Some(1));
+
+    writer.set(1, 20).unwrap();
+    *writer.to_mut().get_mut(2).unwrap().borrow_mut() = 30;
+
+    assert_eq!(writer.to_vec(), vec![1, 20, 30]);
+    assert_eq!(original.to_vec(), vec![1, 2, 3]);
+    assert_eq!(*original.get(1).unwrap().borrow(), 2);
+}
+
+#[test]
+fn unique_list_is_written_in_place() {
+    let mut list = shared(&[1, 2]);
+    let before = list.as_ptr();
+
+    list.append(3);
+    assert_eq!(list.pop_last(), Some(3));
+    assert_eq!(list.as_ptr(), before);
+}
+
+#[test]
+fn copy_happens_once_per_writer() {
+    let original = shared(&[1, 2, 3]);
+    let mut writer = original.clone();
+
+    writer.append(4);
+    let copied = writer.as_ptr();
+    writer.append(5);
+    writer.pop_at(0).unwrap();
+
+    assert_eq!(writer.as_ptr(), copied);
+    assert_eq!(writer.to_vec(), vec![2, 3, 4, 5]);
+    assert_eq!(original.to_vec(), vec![1, 2, 3]);
+}
+
+#[test]
+fn failed_writes_do_not_copy() {
+    let original = shared(&[1]);
+    let mut writer = original.clone();
+
+    assert_eq!(
+        writer.pop_at(3),
+        Err(ListError::IndexOutOfBounds { index: 3, len: 1 })
+    );
+    assert!(writer.insert_to(1, 2).is_err());
+    assert!(writer.shares_with(&original));
+
+    let empty = CowList::<u32>::new();
+    let mut empty_writer = empty.clone();
+    assert_eq!(empty_writer.pop_last(), None);
+    assert!(empty_writer.shares_with(&empty));
+}
+
+#[test]
+fn into_list_copies_only_when_shared() {
+    let original = shared(&[1, 2]);
+    let reader = original.clone();
+
+    let mut list = original.into_list();
+    list.append(3);
+    assert_eq!(list.to_vec(), vec![1, 2, 3]);
+    assert_eq!(reader.to_vec(), vec![1, 2]);
+
+    assert!(reader.is_unique());
+    assert_eq!(reader.into_list().len(), 2);
+}
+
+#[test]
+fn deep_clone_of_a_list_is_independent() {
+    let list = DoublyLinkedList::from(vec![String::from("a"), String::from("b")]);
+    let mut copy = list.clone();
+    copy.set(0, String::from("z")).unwrap();
+
+    assert_eq!(list.to_vec(), vec!["a", "b"]);
+    assert_eq!(copy.to_vec(), vec!["z", "b"]);
+}
@@ -0,0 +1,97 @@
+use std::collections::VecDeque;
+
+use linked_list::deque::{Deque, Queue, Stack};
+use linked_list::linked_list::{DoublyLinkedList, SyncDoublyLinkedList};
+
+fn exercise_deque<L: Deque<Item = u32> + Default>() {
+    let mut list = L::default();
+    assert!(list.is_empty());
+    assert_eq!(list.peek_front(), None);
+    assert_eq!(list.pop_back(), None);
+
+    list.push_back(2);
+    list.push_front(1);
+    list.push_back(3);
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.peek_front(), Some(1));
+    assert_eq!(list.peek_back(), Some(3));
+    assert_eq!(list.peek_back_with(|value| value * 10), Some(30));
+
+    assert_eq!(list.pop_front(), Some(1));
+    assert_eq!(list.pop_back(), Some(3));
+    assert_eq!(list.pop_back(), Some(2));
+    assert_eq!(list.pop_front(), None);
+    assert!(list.is_empty());
+
+    list.push_front(4);
+    assert_eq!(list.peek_back(), Some(4));
+}
+
+fn exercise_adapters<L: Deque<Item = u32> + Default>() {
+    let mut stack: Stack<L> = Stack::new();
+    let mut queue: Queue<L> = Queue::new();
+    for value in 1..=4 {
+        stack.push(value);
+        queue.push(value);
+    }
+
+    assert_eq!(stack.peek(), Some(4));
+    assert_eq!(queue.peek(), Some(1));
+    assert_eq!(stack.len(), 4);
+
+    let popped: Vec<_> = std::iter::from_fn(|| stack.pop()).collect();
+    let dequeued: Vec<_> = std::iter::from_fn(|| queue.pop()).collect();
+    assert_eq!(popped, vec![4, 3, 2, 1]);
+    assert_eq!(dequeued, vec![1, 2, 3, 4]);
+    assert!(stack.is_empty() && queue.is_empty());
+}
+
+#[test]
+fn rc_list_is_a_deque() {
+    exercise_deque::<DoublyLinkedList<u32>>();
+    exercise_adapters::<DoublyLinkedList<u32>>();
+}
+
+#[test]
+fn sync_list_is_a_deque() {
+    exercise_deque::<SyncDoublyLinkedList<u32>>();
+    exercise_adapters::<SyncDoublyLinkedList<u32>>();
+}
+
+#[test]
+fn vec_deque_is_a_deque() {
+    exercise_deque::<VecDeque<u32>>();
+    exercise_adapters::<VecDeque<u32>>();
+}
+
+#[test]
+fn push_front_keeps_the_list_linked() {
+    let mut list = DoublyLinkedList::new();
+    list.push_front(3);
+    list.push_front(2);
+    list.push_front(1);
+    assert_eq!(list.to_vec(), vec![1, 2, 3]);
+
+    list.insert_to(1, 9).unwrap();
+    assert_eq!(list.pop_at(2), Ok(2));
+    assert_eq!(list.pop_last(), Some(3));
+    assert_eq!(list.to_vec(), vec![1, 9]);
+}
+
+#[test]
+fn peek_with_works_without_clone() {
+    struct Job(&'static str);
+
+    let mut queue: Queue<DoublyLinkedList<Job>> = Queue::new();
+    queue.push(Job("build"));
+    queue.push(Job("test"));
+
+    assert_eq!(queue.peek_with(|job| job.0), Some("build"));
+    assert_eq!(queue.pop().map(|job| job.0), Some("build"));
+
+    let list = queue.into_inner();
+    assert_eq!(list.len(), 1);
+
+    let stack = Stack::from(list);
+    assert_eq!(stack.peek_with(|job| job.0.len()), Some(4));
+}
@@ -0,0 +1,115 @@
+use linked_list::{
+    diagnostics::{self, Diagnose},
+    linked_list::DoublyLinkedList,
+    sentinel_list::SentinelList,
+};
+
+fn list_of(n: u32) -> DoublyLinkedList<u32> {
+    let mut list = DoublyLinkedList::new();
+    list.insert_many(0, 0..n).unwrap();
+    list
+}
+
+#[test]
+fn healthy_list_has_one_strong_and_one_weak_reference_per_node() {
+    let mut list = list_of(6);
+    list.insert_to(3, 30).unwrap();
+    list.pop_at(1).unwrap();
+    list.rotate_left(2);
+
+    let report = list.diagnostics();
+    assert!(report.is_healthy(), "{}", report);
+    assert_eq!(report.nodes.len(), 6);
+    assert!(report
+        .nodes
+        .iter()
+        .all(|node| node.strong == 1 && node.weak == 1));
+}
+
+#[test]
+fn held_handles_are_flagged_and_keep_nodes_alive() {
+    let list = list_of(4);
+    let handle = list.iter().nth(2).unwrap();
+
+    let report = list.diagnostics();
+    let flagged: Vec<_> = report.flagged().map(|node| node.index).collect();
+    assert_eq!(flagged, vec![2]);
+    assert_eq!(report.nodes[2].strong, 2);
+
+    let watch = list.watch_nodes();
+    drop(list);
+    assert_eq!(watch.alive(), vec![2]);
+
+    drop(handle);
+    watch.assert_all_freed();
+}
+
+#[test]
+#[should_panic(expected = "1 of 3 nodes still allocated")]
+fn assert_drop_frees_all_reports_leaked_nodes() {
+    let list = list_of(3);
+    let handle = list.iter().next().unwrap();
+
+    diagnostics::assert_drop_frees_all(list);
+    drop(handle);
+}
+
+#[test]
+fn strong_cycle_is_detected() {
+    let list = list_of(3);
+    let head = list.iter().next().unwrap();
+    let tail = list.iter().last().unwrap();
+    tail.borrow_mut().next = Some(head);
+
+    let report = list.diagnostics();
+    assert!(!report.is_healthy());
+    assert_eq!(report.nodes[0].strong, 2);
+    assert!(report.nodes[2].issues[0].contains("cycle"));
+
+    // Break the cycle again so the test itself doesn't leak.
+    tail.borrow_mut().next = None;
+    drop(tail);
+    diagnostics::assert_drop_frees_all(list);
+}
+
+#[test]
+fn every_operation_frees_its_nodes() {
+    let mut list = list_of(20);
+    list.splice(2..5, [7, 8]).unwrap();
+    drop(list.drain(3..6).unwrap());
+    list.rotate_right(4);
+    list.remove_all(&10);
+    assert!(list.diagnostics().is_healthy());
+    diagnostics::assert_drop_frees_all(list);
+
+    let merged = list_of(10).merge(list_of(5), |a, b| a.cmp(b));
+    assert!(merged.diagnostics().is_healthy());
+    diagnostics::assert_drop_frees_all(merged);
+}
+
+#[test]
+fn sentinel_list_diagnostics() {
+    let mut list = SentinelList::new();
+    for value in 0..5 {
+        list.append(value);
+    }
+    list.push_front(9);
+    list.pop_at(2).unwrap();
+
+    let report = list.diagnostics();
+    assert!(report.is_healthy(), "{}", report);
+    assert_eq!(report.nodes.len(), 5);
+
+    let handle = list.iter().next().unwrap();
+    assert_eq!(list.diagnostics().flagged().count(), 1);
+    drop(handle);
+
+    diagnostics::assert_drop_frees_all(list);
+}
+
+#[test]
+fn leaked_values_counts_handles_outliving_the_list() {
+    assert_eq!(diagnostics::leaked_values::<DoublyLinkedList<_>>(100), 0);
+    assert_eq!(diagnostics::leaked_values::<SentinelList<_>>(100), 0);
+    assert_eq!(diagnostics::leaked_values::<Vec<_>>(0), 0);
+}
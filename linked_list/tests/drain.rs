@@ -0,0 +1,123 @@
+use std::rc::{Rc, Weak};
+
+use linked_list::linked_list::{DoublyLinkedList, ListError};
+
+fn list_of(values: &[u32]) -> DoublyLinkedList<u32> {
+    let mut list = DoublyLinkedList::new();
+    for &value in values {
+        list.append(value);
+    }
+    list
+}
+
+// Walks the `next` chain, checking the values and that every `prev` link
+// points back at the node before it; `last` goes through the tail.
+fn assert_list(list: &DoublyLinkedList<u32>, expected: &[u32]) {
+    let nodes: Vec<_> = list.iter().collect();
+    let values: Vec<u32> = nodes
+        .iter()
+        .map(|node| node.borrow().value.unwrap())
+        .collect();
+    assert_eq!(values, expected);
+    assert_eq!(list.len() as usize, expected.len());
+
+    for (index, node) in nodes.iter().enumerate() {
+        let prev = node.borrow().prev.as_ref().map(Weak::as_ptr);
+        let expected_prev = index
+            .checked_sub(1)
+            .map(|before| Rc::as_ptr(&nodes[before]));
+        assert_eq!(prev, expected_prev, "broken prev link at {}", index);
+    }
+    if let Some(&last) = expected.last() {
+        assert_eq!(*list.last().unwrap().borrow(), last);
+    }
+}
+
+fn invalid(start: u32, end: u32, len: u32) -> ListError {
+    ListError::InvalidRange { start, end, len }
+}
+
+#[test]
+fn draining_an_empty_list() {
+    let mut list = DoublyLinkedList::<u32>::new();
+
+    assert_eq!(list.drain(..).unwrap().count(), 0);
+    assert_list(&list.remove_range(0..0).unwrap(), &[]);
+    assert_eq!(list.drain(0..1).err(), Some(invalid(0, 1, 0)));
+}
+
+#[test]
+fn draining_a_single_element() {
+    let mut list = list_of(&[7]);
+
+    let drained: Vec<_> = list.drain(..).unwrap().collect();
+    assert_eq!(drained, vec![7]);
+    assert_list(&list, &[]);
+    list.append(8);
+    assert_list(&list, &[8]);
+}
+
+#[test]
+fn drain_yields_the_range_from_both_ends() {
+    let mut list = list_of(&[0, 1, 2, 3, 4, 5]);
+
+    let mut drain = list.drain(1..5).unwrap();
+    assert_eq!(drain.len(), 4);
+    assert_eq!(drain.next(), Some(1));
+    assert_eq!(drain.next_back(), Some(4));
+    assert_eq!(drain.collect::<Vec<_>>(), vec![2, 3]);
+    assert_list(&list, &[0, 5]);
+}
+
+#[test]
+fn dropping_a_partial_drain_still_removes_the_whole_range() {
+    let mut list = list_of(&[0, 1, 2, 3, 4]);
+
+    let mut drain = list.drain(1..4).unwrap();
+    assert_eq!(drain.next(), Some(1));
+    drop(drain);
+    assert_list(&list, &[0, 4]);
+}
+
+#[test]
+fn remove_range_returns_a_working_list() {
+    let mut list = list_of(&[0, 1, 2, 3, 4]);
+
+    let mut removed = list.remove_range(1..=3).unwrap();
+    assert_list(&list, &[0, 4]);
+    assert_list(&removed, &[1, 2, 3]);
+
+    removed.append(9);
+    assert_eq!(removed.pop_at(0), Ok(1));
+    assert_list(&removed, &[2, 3, 9]);
+}
+
+#[test]
+fn remove_range_at_both_ends() {
+    let mut list = list_of(&[0, 1, 2, 3, 4]);
+
+    assert_list(&list.remove_range(..2).unwrap(), &[0, 1]);
+    assert_list(&list, &[2, 3, 4]);
+    assert_list(&list.remove_range(2..).unwrap(), &[4]);
+    assert_list(&list, &[2, 3]);
+    assert_list(&list.remove_range(..).unwrap(), &[2, 3]);
+    assert_list(&list, &[]);
+}
+
+#[test]
+fn empty_ranges_remove_nothing() {
+    let mut list = list_of(&[0, 1, 2]);
+
+    assert_list(&list.remove_range(1..1).unwrap(), &[]);
+    assert_list(&list.remove_range(3..3).unwrap(), &[]);
+    assert_list(&list, &[0, 1, 2]);
+}
+
+#[test]
+fn invalid_ranges_are_errors() {
+    let mut list = list_of(&[0, 1, 2]);
+
+    assert_eq!(list.remove_range(1..4).err(), Some(invalid(1, 4, 3)));
+    assert_eq!(list.drain(4..).err(), Some(invalid(4, 3, 3)));
+    assert_list(&list, &[0, 1, 2]);
+}
@@ -0,0 +1,41 @@
+use std::collections::VecDeque;
+
+use linked_list::{linked_list::DoublyLinkedList, memory::MemoryUsage};
+
+#[test]
+fn list_breakdown_adds_up_to_the_node_size() {
+    let mut list = DoublyLinkedList::new();
+    for value in 0..10u8 {
+        list.append(value);
+    }
+
+    let usage = list.memory_usage();
+    let components: usize = usage.breakdown.iter().map(|(_, bytes)| bytes).sum();
+
+    assert_eq!(usage.elements, 10);
+    assert_eq!(components, usage.bytes_per_slot);
+    assert_eq!(
+        usage.total_bytes(),
+        usage.header_bytes + 10 * usage.bytes_per_slot
+    );
+    assert!(usage.bytes_per_slot >= 3 * std::mem::size_of::<usize>() + 3);
+}
+
+#[test]
+fn vec_reports_capacity_not_length() {
+    let mut vec = Vec::with_capacity(16);
+    vec.extend(0..4u32);
+    let mut deque = VecDeque::with_capacity(16);
+    deque.extend(0..4u32);
+
+    let vec_usage = MemoryUsage::of_vec(&vec);
+    assert_eq!(vec_usage.allocated, vec.capacity());
+    assert_eq!(
+        vec_usage.total_bytes(),
+        std::mem::size_of::<Vec<u32>>() + vec.capacity() * 4
+    );
+
+    let deque_usage = MemoryUsage::of_vec_deque(&deque);
+    assert_eq!(deque_usage.allocated, deque.capacity());
+    assert_eq!(deque_usage.elements, 4);
+}
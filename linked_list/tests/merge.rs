@@ -0,0 +1,130 @@
+use std::rc::{Rc, Weak};
+
+use linked_list::linked_list::DoublyLinkedList;
+
+fn list_of(values: &[u32]) -> DoublyLinkedList<u32> {
+    let mut list = DoublyLinkedList::new();
+    for &value in values {
+        list.append(value);
+    }
+    list
+}
+
+// Walks the `next` chain, checking the values and that every `prev` link
+// points back at the node before it; `last` goes through the tail.
+fn assert_list(list: &DoublyLinkedList<u32>, expected: &[u32]) {
+    let nodes: Vec<_> = list.iter().collect();
+    let values: Vec<u32> = nodes
+        .iter()
+        .map(|node| node.borrow().value.unwrap())
+        .collect();
+    assert_eq!(values, expected);
+    assert_eq!(list.len() as usize, expected.len());
+
+    for (index, node) in nodes.iter().enumerate() {
+        let prev = node.borrow().prev.as_ref().map(Weak::as_ptr);
+        let expected_prev = index
+            .checked_sub(1)
+            .map(|before| Rc::as_ptr(&nodes[before]));
+        assert_eq!(prev, expected_prev, "broken prev link at {}", index);
+    }
+    if let Some(&last) = expected.last() {
+        assert_eq!(*list.last().unwrap().borrow(), last);
+    }
+}
+
+fn nodes(list: &DoublyLinkedList<u32>) -> Vec<*const ()> {
+    list.iter()
+        .map(|node| Rc::as_ptr(&node) as *const ())
+        .collect()
+}
+
+#[test]
+fn merge_interleaves_sorted_lists() {
+    let left = list_of(&[1, 3, 5, 7]);
+    let right = list_of(&[2, 3, 6]);
+
+    let merged = left.merge(right, u32::cmp);
+    assert_list(&merged, &[1, 2, 3, 3, 5, 6, 7]);
+}
+
+#[test]
+fn merge_reuses_the_input_nodes() {
+    let left = list_of(&[1, 4]);
+    let right = list_of(&[2, 3]);
+    let mut expected = nodes(&left);
+    expected.extend(nodes(&right));
+    expected.sort();
+
+    let merged = left.merge(right, u32::cmp);
+    let mut merged_nodes = nodes(&merged);
+    merged_nodes.sort();
+    assert_eq!(merged_nodes, expected);
+}
+
+#[test]
+fn merge_is_stable_and_honours_the_comparator() {
+    let left = list_of(&[30, 20, 10]);
+    let right = list_of(&[25, 20, 5]);
+
+    // Descending order; equal elements keep `self` first.
+    let merged = left.merge(right, |a, b| b.cmp(a));
+    assert_list(&merged, &[30, 25, 20, 20, 10, 5]);
+}
+
+#[test]
+fn merge_with_empty_and_single_element_lists() {
+    let empty = DoublyLinkedList::new;
+
+    assert_list(&empty().merge(empty(), u32::cmp), &[]);
+    assert_list(&list_of(&[1, 2]).merge(empty(), u32::cmp), &[1, 2]);
+    assert_list(&empty().merge(list_of(&[1, 2]), u32::cmp), &[1, 2]);
+    assert_list(&list_of(&[2]).merge(list_of(&[1]), u32::cmp), &[1, 2]);
+}
+
+#[test]
+fn union_keeps_one_of_each_shared_element() {
+    let left = list_of(&[1, 2, 4, 6]);
+    let right = list_of(&[2, 3, 6, 8, 9]);
+
+    assert_list(&left.union(right), &[1, 2, 3, 4, 6, 8, 9]);
+}
+
+#[test]
+fn union_with_empty_lists() {
+    assert_list(&list_of(&[]).union(list_of(&[])), &[]);
+    assert_list(&list_of(&[1]).union(list_of(&[])), &[1]);
+    assert_list(&list_of(&[]).union(list_of(&[1])), &[1]);
+    assert_list(&list_of(&[1]).union(list_of(&[1])), &[1]);
+}
+
+#[test]
+fn intersection_keeps_shared_elements() {
+    let left = list_of(&[1, 2, 4, 6, 7]);
+    let right = list_of(&[2, 3, 6, 7, 9]);
+
+    assert_list(&left.intersection(right), &[2, 6, 7]);
+}
+
+#[test]
+fn intersection_with_empty_and_disjoint_lists() {
+    assert_list(&list_of(&[]).intersection(list_of(&[1])), &[]);
+    assert_list(&list_of(&[1]).intersection(list_of(&[])), &[]);
+    assert_list(&list_of(&[1, 3]).intersection(list_of(&[2, 4])), &[]);
+    assert_list(&list_of(&[5]).intersection(list_of(&[5])), &[5]);
+}
+
+#[test]
+fn difference_keeps_elements_only_in_self() {
+    let left = list_of(&[1, 2, 4, 6, 7]);
+    let right = list_of(&[2, 3, 6]);
+
+    assert_list(&left.difference(right), &[1, 4, 7]);
+}
+
+#[test]
+fn difference_with_empty_lists() {
+    assert_list(&list_of(&[]).difference(list_of(&[1])), &[]);
+    assert_list(&list_of(&[1, 2]).difference(list_of(&[])), &[1, 2]);
+    assert_list(&list_of(&[1]).difference(list_of(&[1])), &[]);
+}
@@ -0,0 +1,99 @@
+use std::{
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use linked_list::linked_list::{
+    ArcMutexFamily, ArcRwLockFamily, DoublyLinkedList, PointerFamily, RcFamily,
+    SyncDoublyLinkedList,
+};
+
+fn drain_back<T, F: PointerFamily>(list: &mut DoublyLinkedList<T, F>) -> Vec<T> {
+    let mut values = Vec::new();
+    while let Some(value) = list.pop_last() {
+        values.push(value);
+    }
+    values.reverse();
+    values
+}
+
+fn exercise_core_operations<F: PointerFamily>() {
+    let mut list: DoublyLinkedList<u32, F> = DoublyLinkedList::default();
+
+    for value in [1, 2, 4, 5] {
+        list.append(value);
+    }
+    list.insert_to(2, 3).unwrap();
+    list.insert_to(0, 0).unwrap();
+    assert_eq!(list.len(), 6);
+
+    assert_eq!(list.pop_at(5), Ok(5));
+    assert_eq!(list.pop_at(0), Ok(0));
+    assert_eq!(list.pop_at(1), Ok(2));
+    assert!(list.pop_at(3).is_err());
+    assert!(list.insert_to(3, 9).is_err());
+
+    assert_eq!(drain_back(&mut list), vec![1, 3, 4]);
+    assert!(list.is_empty());
+    assert_eq!(list.pop_last(), None);
+
+    list.append(7);
+    assert_eq!(drain_back(&mut list), vec![7]);
+}
+
+#[test]
+fn core_operations_with_rc_refcell() {
+    exercise_core_operations::<RcFamily>();
+}
+
+#[test]
+fn core_operations_with_arc_mutex() {
+    exercise_core_operations::<ArcMutexFamily>();
+}
+
+#[test]
+fn core_operations_with_arc_rwlock() {
+    exercise_core_operations::<ArcRwLockFamily>();
+}
+
+#[test]
+fn arc_list_can_be_moved_to_another_thread() {
+    let mut list = SyncDoublyLinkedList::default();
+    for value in 0..100 {
+        list.append(value);
+    }
+
+    let mut list = thread::spawn(move || {
+        list.pop_at(0).unwrap();
+        list
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(drain_back(&mut list), (1..100).collect::<Vec<_>>());
+}
+
+#[test]
+fn arc_list_can_be_shared_between_threads() {
+    let list = Arc::new(Mutex::new(SyncDoublyLinkedList::default()));
+
+    let workers: Vec<_> = (0..4)
+        .map(|worker| {
+            let list = Arc::clone(&list);
+            thread::spawn(move || {
+                for value in 0..250 {
+                    list.lock().unwrap().append(worker * 1000 + value);
+                }
+            })
+        })
+        .collect();
+    for worker in workers {
+        worker.join().unwrap();
+    }
+
+    let mut values = drain_back(&mut list.lock().unwrap());
+    assert_eq!(values.len(), 1000);
+    values.sort();
+    values.dedup();
+    assert_eq!(values.len(), 1000);
+}
@@ -0,0 +1,178 @@
+use std::rc::Rc;
+
+use linked_list::linked_list::{DoublyLinkedList, SyncDoublyLinkedList};
+use linked_list::random::XorShift;
+
+fn values(list: &DoublyLinkedList<u32>) -> Vec<u32> {
+    list.to_vec()
+}
+
+#[test]
+fn same_seed_same_sequence() {
+    let mut a = XorShift::new(42);
+    let mut b = XorShift::new(42);
+    let mut c = XorShift::new(43);
+
+    let from_a: Vec<_> = (0..16).map(|_| a.next_u64()).collect();
+    let from_b: Vec<_> = (0..16).map(|_| b.next_u64()).collect();
+    let from_c: Vec<_> = (0..16).map(|_| c.next_u64()).collect();
+    assert_eq!(from_a, from_b);
+    assert_ne!(from_a, from_c);
+}
+
+#[test]
+fn zero_seed_still_produces_numbers() {
+    let mut rng = XorShift::new(0);
+    assert_ne!(rng.next_u64(), 0);
+}
+
+#[test]
+fn below_stays_in_range_and_covers_it() {
+    let mut rng = XorShift::new(7);
+    let mut seen = [0u32; 6];
+    for _ in 0..6000 {
+        let value = rng.below(6);
+        assert!(value < 6);
+        seen[value as usize] += 1;
+    }
+    assert!(
+        seen.iter().all(|&count| (800..1200).contains(&count)),
+        "{:?}",
+        seen
+    );
+}
+
+#[test]
+#[should_panic(expected = "non-zero bound")]
+fn below_zero_panics() {
+    XorShift::new(1).below(0);
+}
+
+#[test]
+fn shuffle_is_a_reproducible_permutation() {
+    let mut list: DoublyLinkedList<u32> = (0..50).collect();
+    let mut other: DoublyLinkedList<u32> = (0..50).collect();
+
+    list.shuffle(&mut XorShift::new(9));
+    other.shuffle(&mut XorShift::new(9));
+    assert_eq!(values(&list), values(&other));
+    assert_ne!(values(&list), (0..50).collect::<Vec<_>>());
+
+    let mut sorted = values(&list);
+    sorted.sort();
+    assert_eq!(sorted, (0..50).collect::<Vec<_>>());
+    assert_eq!(list.len(), 50);
+}
+
+#[test]
+fn shuffle_relinks_nodes_instead_of_moving_values() {
+    let mut list: DoublyLinkedList<u32> = (0..20).collect();
+    let before: Vec<_> = list
+        .iter()
+        .map(|node| (Rc::as_ptr(&node), node.borrow().value))
+        .collect();
+
+    list.shuffle(&mut XorShift::new(3));
+
+    for node in list.iter() {
+        let (_, value) = before
+            .iter()
+            .find(|(ptr, _)| *ptr == Rc::as_ptr(&node))
+            .expect("shuffle allocated a new node");
+        assert_eq!(node.borrow().value, *value);
+    }
+
+    // The links still agree in both directions.
+    let backwards: Vec<_> = std::iter::from_fn(|| list.pop_last()).collect();
+    let mut forwards = backwards.clone();
+    forwards.reverse();
+    assert_eq!(backwards.len(), 20);
+    assert!(list.is_empty());
+    assert_ne!(forwards, (0..20).collect::<Vec<_>>());
+}
+
+#[test]
+fn shuffle_handles_short_lists() {
+    let mut rng = XorShift::new(1);
+    let mut empty = DoublyLinkedList::<u32>::new();
+    empty.shuffle(&mut rng);
+    assert!(empty.is_empty());
+
+    let mut single: DoublyLinkedList<u32> = std::iter::once(5).collect();
+    single.shuffle(&mut rng);
+    assert_eq!(values(&single), vec![5]);
+}
+
+#[test]
+fn shuffle_works_on_sync_lists() {
+    let mut list: SyncDoublyLinkedList<u32> = (0..10).collect();
+    list.shuffle(&mut XorShift::new(5));
+
+    let mut shuffled = list.to_vec();
+    assert_ne!(shuffled, (0..10).collect::<Vec<_>>());
+    shuffled.sort();
+    assert_eq!(shuffled, (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn choose_picks_every_element_eventually() {
+    let list: DoublyLinkedList<u32> = (0..5).collect();
+    let mut rng = XorShift::new(11);
+    let mut seen = [false; 5];
+
+    for _ in 0..200 {
+        let element = list.choose(&mut rng).expect("list is not empty");
+        seen[*element.borrow() as usize] = true;
+    }
+    assert!(seen.iter().all(|&hit| hit));
+
+    assert!(DoublyLinkedList::<u32>::new().choose(&mut rng).is_none());
+}
+
+#[test]
+fn sample_returns_distinct_elements() {
+    let list: DoublyLinkedList<u32> = (0..100).collect();
+    let sample = list.sample(10, &mut XorShift::new(2));
+
+    let mut picked: Vec<u32> = sample.iter().map(|element| *element.borrow()).collect();
+    assert_eq!(picked.len(), 10);
+    picked.sort();
+    picked.dedup();
+    assert_eq!(picked.len(), 10);
+}
+
+#[test]
+fn sample_larger_than_the_list_takes_everything() {
+    let list: DoublyLinkedList<u32> = (0..4).collect();
+    let sample = list.sample(10, &mut XorShift::new(2));
+
+    let picked: Vec<u32> = sample.iter().map(|element| *element.borrow()).collect();
+    assert_eq!(picked, vec![0, 1, 2, 3]);
+    assert!(list.sample(0, &mut XorShift::new(2)).is_empty());
+}
+
+#[test]
+fn sample_is_reproducible_and_roughly_uniform() {
+    let list: DoublyLinkedList<u32> = (0..10).collect();
+    let draw = |seed| -> Vec<u32> {
+        list.sample(3, &mut XorShift::new(seed))
+            .iter()
+            .map(|element| *element.borrow())
+            .collect()
+    };
+    assert_eq!(draw(8), draw(8));
+
+    // Each element should land in about 3 out of 10 samples.
+    let mut counts = [0u32; 10];
+    let mut rng = XorShift::new(4);
+    for _ in 0..3000 {
+        for element in list.sample(3, &mut rng) {
+            counts[*element.borrow() as usize] += 1;
+        }
+    }
+    assert!(
+        counts.iter().all(|&count| (780..1020).contains(&count)),
+        "{:?}",
+        counts
+    );
+}
@@ -0,0 +1,101 @@
+use std::rc::{Rc, Weak};
+
+use linked_list::linked_list::DoublyLinkedList;
+
+fn list_of(values: &[u32]) -> DoublyLinkedList<u32> {
+    let mut list = DoublyLinkedList::new();
+    for &value in values {
+        list.append(value);
+    }
+    list
+}
+
+// Walks the `next` chain, checking the values and that every `prev` link
+// points back at the node before it; `last` goes through the tail.
+fn assert_list(list: &DoublyLinkedList<u32>, expected: &[u32]) {
+    let nodes: Vec<_> = list.iter().collect();
+    let values: Vec<u32> = nodes
+        .iter()
+        .map(|node| node.borrow().value.unwrap())
+        .collect();
+    assert_eq!(values, expected);
+    assert_eq!(list.len() as usize, expected.len());
+
+    for (index, node) in nodes.iter().enumerate() {
+        let prev = node.borrow().prev.as_ref().map(Weak::as_ptr);
+        let expected_prev = index
+            .checked_sub(1)
+            .map(|before| Rc::as_ptr(&nodes[before]));
+        assert_eq!(prev, expected_prev, "broken prev link at {}", index);
+    }
+    if let Some(&last) = expected.last() {
+        assert_eq!(*list.last().unwrap().borrow(), last);
+    }
+}
+
+#[test]
+fn rotating_an_empty_list_does_nothing() {
+    let mut list = DoublyLinkedList::<u32>::new();
+
+    list.rotate_left(3);
+    list.rotate_right(3);
+    assert_list(&list, &[]);
+}
+
+#[test]
+fn rotating_a_single_element_does_nothing() {
+    let mut list = list_of(&[1]);
+
+    list.rotate_left(1);
+    list.rotate_right(5);
+    assert_list(&list, &[1]);
+}
+
+#[test]
+fn rotate_left_moves_the_front_to_the_back() {
+    let mut list = list_of(&[0, 1, 2, 3, 4]);
+
+    list.rotate_left(2);
+    assert_list(&list, &[2, 3, 4, 0, 1]);
+    list.rotate_left(4);
+    assert_list(&list, &[1, 2, 3, 4, 0]);
+}
+
+#[test]
+fn rotate_right_moves_the_back_to_the_front() {
+    let mut list = list_of(&[0, 1, 2, 3, 4]);
+
+    list.rotate_right(1);
+    assert_list(&list, &[4, 0, 1, 2, 3]);
+    list.rotate_right(3);
+    assert_list(&list, &[1, 2, 3, 4, 0]);
+}
+
+#[test]
+fn rotating_by_the_length_or_more_wraps_around() {
+    let mut list = list_of(&[0, 1, 2]);
+
+    list.rotate_left(0);
+    list.rotate_left(3);
+    list.rotate_right(6);
+    assert_list(&list, &[0, 1, 2]);
+
+    list.rotate_left(4);
+    assert_list(&list, &[1, 2, 0]);
+
+    // u32::MAX leaves 3 over a length of 4, the same as rotating left by 1.
+    let mut list = list_of(&[0, 1, 2, 3]);
+    list.rotate_right(u32::MAX);
+    assert_list(&list, &[1, 2, 3, 0]);
+}
+
+#[test]
+fn rotation_keeps_the_nodes() {
+    let mut list = list_of(&[0, 1, 2, 3]);
+    let mut before: Vec<_> = list.iter().map(|node| Rc::as_ptr(&node)).collect();
+
+    list.rotate_left(1);
+    before.rotate_left(1);
+    let after: Vec<_> = list.iter().map(|node| Rc::as_ptr(&node)).collect();
+    assert_eq!(after, before);
+}
@@ -0,0 +1,130 @@
+use std::rc::{Rc, Weak};
+
+use linked_list::linked_list::DoublyLinkedList;
+
+fn list_of(values: &[u32]) -> DoublyLinkedList<u32> {
+    let mut list = DoublyLinkedList::new();
+    for &value in values {
+        list.append(value);
+    }
+    list
+}
+
+// Walks the `next` chain, checking the values and that every `prev` link
+// points back at the node before it; `last` goes through the tail.
+fn assert_list(list: &DoublyLinkedList<u32>, expected: &[u32]) {
+    let nodes: Vec<_> = list.iter().collect();
+    let values: Vec<u32> = nodes
+        .iter()
+        .map(|node| node.borrow().value.unwrap())
+        .collect();
+    assert_eq!(values, expected);
+    assert_eq!(list.len() as usize, expected.len());
+
+    for (index, node) in nodes.iter().enumerate() {
+        let prev = node.borrow().prev.as_ref().map(Weak::as_ptr);
+        let expected_prev = index
+            .checked_sub(1)
+            .map(|before| Rc::as_ptr(&nodes[before]));
+        assert_eq!(prev, expected_prev, "broken prev link at {}", index);
+    }
+    if let Some(&last) = expected.last() {
+        assert_eq!(*list.last().unwrap().borrow(), last);
+    }
+}
+
+#[test]
+fn searching_an_empty_list_finds_nothing() {
+    let mut list = DoublyLinkedList::<u32>::new();
+
+    assert!(!list.contains(&1));
+    assert_eq!(list.position(|_| true), None);
+    assert_eq!(list.rposition(|_| true), None);
+    assert_eq!(list.find_map(|&value| Some(value)), None);
+    assert_eq!(list.remove_first(&1), None);
+    assert_eq!(list.remove_all(&1), 0);
+}
+
+#[test]
+fn single_element() {
+    let list = list_of(&[5]);
+
+    assert!(list.contains(&5));
+    assert!(!list.contains(&6));
+    assert_eq!(list.position(|&value| value == 5), Some(0));
+    assert_eq!(list.rposition(|&value| value == 5), Some(0));
+    assert_eq!(list.find_map(|&value| value.checked_sub(1)), Some(4));
+}
+
+#[test]
+fn position_and_rposition_find_opposite_matches() {
+    let list = list_of(&[1, 2, 1, 3, 1]);
+
+    assert_eq!(list.position(|&value| value == 1), Some(0));
+    assert_eq!(list.rposition(|&value| value == 1), Some(4));
+    assert_eq!(list.position(|&value| value == 3), Some(3));
+    assert_eq!(list.rposition(|&value| value == 2), Some(1));
+    assert_eq!(list.position(|&value| value > 3), None);
+    assert_eq!(list.rposition(|&value| value > 3), None);
+}
+
+#[test]
+fn search_stops_at_the_first_match() {
+    let list = list_of(&[1, 2, 3, 4]);
+    let mut visited = Vec::new();
+
+    let found = list.find_map(|&value| {
+        visited.push(value);
+        (value % 2 == 0).then_some(value * 10)
+    });
+    assert_eq!(found, Some(20));
+    assert_eq!(visited, vec![1, 2]);
+
+    let mut visited = Vec::new();
+    list.rposition(|&value| {
+        visited.push(value);
+        value == 3
+    });
+    assert_eq!(visited, vec![4, 3]);
+}
+
+#[test]
+fn remove_first_unlinks_only_the_first_match() {
+    let mut list = list_of(&[1, 2, 1, 3]);
+
+    assert_eq!(list.remove_first(&1), Some(1));
+    assert_list(&list, &[2, 1, 3]);
+    assert_eq!(list.remove_first(&3), Some(3));
+    assert_list(&list, &[2, 1]);
+    assert_eq!(list.remove_first(&9), None);
+    assert_list(&list, &[2, 1]);
+}
+
+#[test]
+fn remove_first_of_the_only_element_empties_the_list() {
+    let mut list = list_of(&[4]);
+
+    assert_eq!(list.remove_first(&4), Some(4));
+    assert_list(&list, &[]);
+    list.append(5);
+    assert_list(&list, &[5]);
+}
+
+#[test]
+fn remove_all_unlinks_every_match_including_both_ends() {
+    let mut list = list_of(&[7, 1, 7, 7, 2, 7]);
+
+    assert_eq!(list.remove_all(&7), 4);
+    assert_list(&list, &[1, 2]);
+    assert_eq!(list.remove_all(&7), 0);
+    assert_list(&list, &[1, 2]);
+}
+
+#[test]
+fn remove_all_can_empty_the_list() {
+    let mut list = list_of(&[3, 3, 3]);
+
+    assert_eq!(list.remove_all(&3), 3);
+    assert_list(&list, &[]);
+    assert_eq!(list.pop_last(), None);
+}
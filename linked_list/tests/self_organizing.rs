@@ -0,0 +1,98 @@
+use linked_list::linked_list::{PromotionPolicy, SelfOrganizingList};
+
+fn list_of(policy: PromotionPolicy, values: &[char]) -> SelfOrganizingList<char> {
+    let mut list = SelfOrganizingList::new(policy);
+    for &value in values {
+        list.insert(value);
+    }
+    list
+}
+
+#[test]
+fn move_to_front_jumps_to_the_head() {
+    let mut list = list_of(PromotionPolicy::MoveToFront, &['a', 'b', 'c', 'd']);
+
+    assert_eq!(*list.find(|&c| c == 'c').unwrap().borrow(), 'c');
+    assert_eq!(list.to_vec(), vec!['c', 'a', 'b', 'd']);
+
+    assert!(list.contains(&'d'));
+    assert_eq!(list.to_vec(), vec!['d', 'c', 'a', 'b']);
+
+    // Already at the front: nothing moves.
+    assert!(list.contains(&'d'));
+    assert_eq!(list.to_vec(), vec!['d', 'c', 'a', 'b']);
+}
+
+#[test]
+fn transpose_moves_one_step() {
+    let mut list = list_of(PromotionPolicy::Transpose, &['a', 'b', 'c', 'd']);
+
+    assert!(list.contains(&'d'));
+    assert_eq!(list.to_vec(), vec!['a', 'b', 'd', 'c']);
+    assert!(list.contains(&'d'));
+    assert!(list.contains(&'d'));
+    assert_eq!(list.to_vec(), vec!['d', 'a', 'b', 'c']);
+    assert!(list.contains(&'d'));
+    assert_eq!(list.to_vec(), vec!['d', 'a', 'b', 'c']);
+}
+
+#[test]
+fn frequency_count_orders_by_hits() {
+    let mut list = list_of(PromotionPolicy::FrequencyCount, &['a', 'b', 'c', 'd']);
+
+    assert_eq!(list.find(|&c| c == 'c').unwrap().hits(), 1);
+    assert_eq!(list.to_vec(), vec!['c', 'a', 'b', 'd']);
+
+    // Ties keep their order: 'd' passes the unvisited ones but not 'c'.
+    assert!(list.contains(&'d'));
+    assert_eq!(list.to_vec(), vec!['c', 'd', 'a', 'b']);
+
+    assert!(list.contains(&'d'));
+    assert_eq!(list.to_vec(), vec!['d', 'c', 'a', 'b']);
+
+    assert!(list.contains(&'b'));
+    assert!(list.contains(&'b'));
+    assert!(list.contains(&'b'));
+    assert_eq!(list.to_vec(), vec!['b', 'd', 'c', 'a']);
+    assert_eq!(list.find(|&c| c == 'b').unwrap().hits(), 4);
+}
+
+#[test]
+fn misses_and_removal_leave_the_order_alone() {
+    let mut list = list_of(PromotionPolicy::MoveToFront, &['a', 'b', 'c']);
+
+    assert!(list.find(|&c| c == 'z').is_none());
+    assert_eq!(list.remove(|&c| c == 'b'), Some('b'));
+    assert_eq!(list.to_vec(), vec!['a', 'c']);
+    assert_eq!(list.len(), 2);
+
+    assert!(list.contains(&'c'));
+    assert_eq!(list.to_vec(), vec!['c', 'a']);
+    assert_eq!(list.remove(|&c| c == 'a'), Some('a'));
+    assert_eq!(list.remove(|&c| c == 'c'), Some('c'));
+    assert!(list.is_empty());
+}
+
+#[test]
+fn promotion_keeps_links_consistent() {
+    for policy in [
+        PromotionPolicy::MoveToFront,
+        PromotionPolicy::Transpose,
+        PromotionPolicy::FrequencyCount,
+    ] {
+        let mut list = SelfOrganizingList::new(policy);
+        for value in 0..50u32 {
+            list.insert(value);
+        }
+        // Promotions walk and rewrite `prev` links, so a broken link shows
+        // up as a lost or duplicated element.
+        for step in 0..500u32 {
+            let target = (step * 7 + step / 3) % 50;
+            assert!(list.contains(&target));
+        }
+
+        let mut order = list.to_vec();
+        order.sort();
+        assert_eq!(order, (0..50).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,144 @@
+use std::rc::Rc;
+
+use linked_list::{linked_list::ListError, sentinel_list::SentinelList};
+
+// Walks the ring from the first real node back round to it, checking that
+// every `prev` mirrors the `next` before it and that exactly one node, the
+// sentinel, has no value.
+fn assert_ring(list: &SentinelList<u32>, expected: &[u32]) {
+    let nodes: Vec<_> = list.iter().collect();
+    let values: Vec<_> = nodes
+        .iter()
+        .map(|node| node.borrow().value.unwrap())
+        .collect();
+    assert_eq!(values, expected);
+    assert_eq!(list.len() as usize, expected.len());
+    assert_eq!(list.is_empty(), expected.is_empty());
+
+    let (Some(first), Some(last)) = (nodes.first(), nodes.last()) else {
+        return;
+    };
+    let sentinel = first.borrow().prev.upgrade().expect("head without a prev");
+    assert!(sentinel.borrow().value.is_none());
+
+    let head = sentinel
+        .borrow()
+        .next
+        .clone()
+        .expect("sentinel without a next");
+    assert!(Rc::ptr_eq(&head, first));
+    let tail = sentinel
+        .borrow()
+        .prev
+        .upgrade()
+        .expect("sentinel without a prev");
+    assert!(Rc::ptr_eq(&tail, last));
+    let after_last = last.borrow().next.clone().expect("tail without a next");
+    assert!(Rc::ptr_eq(&after_last, &sentinel));
+
+    for pair in nodes.windows(2) {
+        let next = pair[0].borrow().next.clone().expect("node without a next");
+        assert!(Rc::ptr_eq(&next, &pair[1]));
+        let prev = pair[1]
+            .borrow()
+            .prev
+            .upgrade()
+            .expect("node without a prev");
+        assert!(Rc::ptr_eq(&prev, &pair[0]));
+    }
+}
+
+fn list_of(values: &[u32]) -> SentinelList<u32> {
+    let mut list = SentinelList::new();
+    for &value in values {
+        list.append(value);
+    }
+    list
+}
+
+#[test]
+fn empty_list_pops_nothing() {
+    let mut list = SentinelList::<u32>::new();
+
+    assert_ring(&list, &[]);
+    assert_eq!(list.pop_front(), None);
+    assert_eq!(list.pop_last(), None);
+    assert_eq!(
+        list.pop_at(0),
+        Err(ListError::IndexOutOfBounds { index: 0, len: 0 })
+    );
+    assert_eq!(
+        list.insert_to(0, 1),
+        Err(ListError::IndexOutOfBounds { index: 0, len: 0 })
+    );
+    assert_ring(&list, &[]);
+}
+
+#[test]
+fn single_element_closes_the_ring_through_the_sentinel() {
+    let mut list = SentinelList::new();
+
+    list.append(1);
+    assert_ring(&list, &[1]);
+    assert_eq!(list.pop_last(), Some(1));
+    assert_ring(&list, &[]);
+
+    list.push_front(2);
+    assert_ring(&list, &[2]);
+    assert_eq!(list.pop_front(), Some(2));
+    assert_ring(&list, &[]);
+}
+
+#[test]
+fn push_at_both_ends() {
+    let mut list = SentinelList::new();
+
+    list.append(2);
+    list.push_front(1);
+    list.append(3);
+    list.push_front(0);
+    assert_ring(&list, &[0, 1, 2, 3]);
+}
+
+#[test]
+fn removal_at_both_ends() {
+    let mut list = list_of(&[0, 1, 2, 3, 4]);
+
+    assert_eq!(list.pop_front(), Some(0));
+    assert_ring(&list, &[1, 2, 3, 4]);
+    assert_eq!(list.pop_last(), Some(4));
+    assert_ring(&list, &[1, 2, 3]);
+    assert_eq!(list.pop_at(0), Ok(1));
+    assert_ring(&list, &[2, 3]);
+    assert_eq!(list.pop_at(1), Ok(3));
+    assert_ring(&list, &[2]);
+    assert_eq!(list.pop_front(), Some(2));
+    assert_ring(&list, &[]);
+}
+
+#[test]
+fn insert_and_remove_in_the_middle() {
+    let mut list = list_of(&[0, 2, 4]);
+
+    list.insert_to(1, 1).unwrap();
+    list.insert_to(3, 3).unwrap();
+    assert_ring(&list, &[0, 1, 2, 3, 4]);
+    assert_eq!(list.pop_at(2), Ok(2));
+    assert_ring(&list, &[0, 1, 3, 4]);
+    assert_eq!(
+        list.pop_at(4),
+        Err(ListError::IndexOutOfBounds { index: 4, len: 4 })
+    );
+}
+
+#[test]
+fn dropping_frees_every_node() {
+    let value = Rc::new(());
+    let mut list = SentinelList::new();
+    for _ in 0..100 {
+        list.append(Rc::clone(&value));
+    }
+
+    drop(list);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
@@ -0,0 +1,160 @@
+use std::{
+    ops::Bound,
+    panic::{self, AssertUnwindSafe},
+    rc::{Rc, Weak},
+};
+
+use linked_list::linked_list::{DoublyLinkedList, ListError};
+
+fn list_of(values: &[u32]) -> DoublyLinkedList<u32> {
+    let mut list = DoublyLinkedList::new();
+    for &value in values {
+        list.append(value);
+    }
+    list
+}
+
+// Walks the `next` chain, checking the values and that every `prev` link
+// points back at the node before it; `last` goes through the tail.
+fn assert_list(list: &DoublyLinkedList<u32>, expected: &[u32]) {
+    let nodes: Vec<_> = list.iter().collect();
+    let values: Vec<u32> = nodes
+        .iter()
+        .map(|node| node.borrow().value.unwrap())
+        .collect();
+    assert_eq!(values, expected);
+    assert_eq!(list.len() as usize, expected.len());
+
+    for (index, node) in nodes.iter().enumerate() {
+        let prev = node.borrow().prev.as_ref().map(Weak::as_ptr);
+        let expected_prev = index
+            .checked_sub(1)
+            .map(|before| Rc::as_ptr(&nodes[before]));
+        assert_eq!(prev, expected_prev, "broken prev link at {}", index);
+    }
+    if let Some(&last) = expected.last() {
+        assert_eq!(*list.last().unwrap().borrow(), last);
+    }
+}
+
+fn invalid(start: u32, end: u32, len: u32) -> ListError {
+    ListError::InvalidRange { start, end, len }
+}
+
+#[test]
+fn ranges_ending_past_u32_max_are_rejected() {
+    let mut list = list_of(&[1, 2, 3]);
+
+    assert_eq!(list.splice(0..=u32::MAX, [9]), Err(invalid(0, u32::MAX, 3)));
+    assert_eq!(
+        list.remove_range(0..=u32::MAX).err(),
+        Some(invalid(0, u32::MAX, 3))
+    );
+    let excluded_max = (Bound::Excluded(u32::MAX), Bound::Unbounded);
+    assert_eq!(
+        list.splice(excluded_max, []),
+        Err(invalid(u32::MAX, u32::MAX, 3))
+    );
+    assert_list(&list, &[1, 2, 3]);
+}
+
+#[test]
+fn panicking_replacement_leaves_the_list_untouched() {
+    let mut list = list_of(&[1, 2, 3, 4]);
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let replacement = (10..).inspect(|&value| {
+            if value == 12 {
+                panic!("replacement failed");
+            }
+        });
+        list.splice(1..3, replacement)
+    }));
+
+    assert!(result.is_err());
+    assert_list(&list, &[1, 2, 3, 4]);
+    assert_eq!(list.splice(1..3, [20]), Ok(vec![2, 3]));
+    assert_list(&list, &[1, 20, 4]);
+}
+
+#[test]
+fn insert_many_into_an_empty_list() {
+    let mut list = DoublyLinkedList::new();
+
+    list.insert_many(0, [1, 2, 3]).unwrap();
+    assert_list(&list, &[1, 2, 3]);
+
+    let mut list = DoublyLinkedList::<u32>::new();
+    list.insert_many(0, []).unwrap();
+    assert_list(&list, &[]);
+    assert_eq!(list.insert_many(1, [1]), Err(invalid(1, 1, 0)));
+}
+
+#[test]
+fn insert_many_at_the_front_middle_and_end() {
+    let mut list = list_of(&[5]);
+
+    list.insert_many(0, [1, 2]).unwrap();
+    assert_list(&list, &[1, 2, 5]);
+    list.insert_many(2, [3, 4]).unwrap();
+    assert_list(&list, &[1, 2, 3, 4, 5]);
+    list.insert_many(5, [6, 7]).unwrap();
+    assert_list(&list, &[1, 2, 3, 4, 5, 6, 7]);
+    list.insert_many(3, []).unwrap();
+    assert_list(&list, &[1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn insert_many_past_the_end_is_an_error() {
+    let mut list = list_of(&[1, 2]);
+
+    assert_eq!(list.insert_many(3, [9]), Err(invalid(3, 3, 2)));
+    assert_list(&list, &[1, 2]);
+}
+
+#[test]
+fn splice_returns_the_replaced_elements() {
+    let mut list = list_of(&[0, 1, 2, 3, 4]);
+
+    assert_eq!(list.splice(1..3, [10, 11, 12]), Ok(vec![1, 2]));
+    assert_list(&list, &[0, 10, 11, 12, 3, 4]);
+}
+
+#[test]
+fn splice_at_the_ends() {
+    let mut list = list_of(&[0, 1, 2, 3]);
+
+    assert_eq!(list.splice(..1, [9]), Ok(vec![0]));
+    assert_list(&list, &[9, 1, 2, 3]);
+    assert_eq!(list.splice(2.., [8, 7]), Ok(vec![2, 3]));
+    assert_list(&list, &[9, 1, 8, 7]);
+}
+
+#[test]
+fn splice_with_an_empty_replacement_removes_the_range() {
+    let mut list = list_of(&[0, 1, 2, 3]);
+
+    assert_eq!(list.splice(1..=2, []), Ok(vec![1, 2]));
+    assert_list(&list, &[0, 3]);
+    assert_eq!(list.splice(.., []), Ok(vec![0, 3]));
+    assert_list(&list, &[]);
+}
+
+#[test]
+fn splice_the_whole_list() {
+    let mut list = list_of(&[1]);
+
+    assert_eq!(list.splice(.., [4, 5, 6]), Ok(vec![1]));
+    assert_list(&list, &[4, 5, 6]);
+}
+
+#[test]
+fn splice_rejects_invalid_ranges() {
+    let mut list = list_of(&[1, 2, 3]);
+
+    assert_eq!(list.splice(2..4, [0]), Err(invalid(2, 4, 3)));
+    #[allow(clippy::reversed_empty_ranges)]
+    let backwards = 2..1;
+    assert_eq!(list.splice(backwards, [0]), Err(invalid(2, 1, 3)));
+    assert_list(&list, &[1, 2, 3]);
+}
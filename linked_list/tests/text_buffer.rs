@@ -0,0 +1,131 @@
+use std::{env, fs, process};
+
+use linked_list::text_buffer::{EditError, TextBuffer};
+
+#[test]
+fn edits_can_be_undone_and_redone() {
+    let mut buffer = TextBuffer::from_text("fn main() {\n}\n");
+
+    buffer.insert_line(1, "    let x = 1;").unwrap();
+    buffer.split_line(1, 14).unwrap();
+    buffer.insert_line(2, "    println!(\"{}\", x);").unwrap();
+    buffer.delete_line(3).unwrap();
+    assert_eq!(
+        buffer.contents(),
+        "fn main() {\n    let x = 1;\n    println!(\"{}\", x);\n}\n"
+    );
+
+    buffer.join_lines(0).unwrap();
+    assert_eq!(
+        *buffer.line(0).unwrap().borrow(),
+        "fn main() {    let x = 1;"
+    );
+
+    let mut states = vec![buffer.contents()];
+    while buffer.undo() {
+        states.push(buffer.contents());
+    }
+    assert_eq!(buffer.contents(), "fn main() {\n}\n");
+    assert!(!buffer.can_undo());
+
+    states.pop();
+    while buffer.redo() {
+        assert_eq!(buffer.contents(), states.pop().unwrap());
+    }
+    assert!(states.is_empty());
+}
+
+#[test]
+fn new_edit_clears_redo_history() {
+    let mut buffer = TextBuffer::from_text("a\nb\n");
+
+    buffer.delete_line(0).unwrap();
+    assert!(buffer.undo());
+    assert!(buffer.can_redo());
+
+    buffer.insert_line(2, "c").unwrap();
+    assert!(!buffer.can_redo());
+    assert_eq!(buffer.contents(), "a\nb\nc\n");
+}
+
+#[test]
+fn invalid_edits_are_rejected_without_touching_history() {
+    let mut buffer = TextBuffer::from_text("héllo\nworld\n");
+
+    assert_eq!(
+        buffer.split_line(0, 2),
+        Err(EditError::InvalidColumn { line: 0, column: 2 })
+    );
+    assert!(matches!(buffer.join_lines(1), Err(EditError::Index(_))));
+    assert!(matches!(buffer.delete_line(5), Err(EditError::Index(_))));
+    assert!(!buffer.can_undo());
+    assert_eq!(buffer.contents(), "héllo\nworld\n");
+}
+
+#[test]
+fn cursor_and_search() {
+    let mut buffer = TextBuffer::from_text("todo: one\ndone\ntodo: two\nmore todo\n");
+
+    assert_eq!(buffer.find("todo"), Some((0, 0)));
+    assert_eq!(buffer.find_all("todo"), vec![(0, 0), (2, 0), (3, 5)]);
+
+    assert_eq!(buffer.find_next("todo"), Some((2, 0)));
+    assert_eq!(buffer.find_next("todo"), Some((3, 5)));
+    assert_eq!(buffer.find_next("todo"), Some((0, 0)));
+    assert_eq!(buffer.cursor(), 0);
+
+    assert!(buffer.move_down());
+    assert_eq!(*buffer.current_line().unwrap().borrow(), "done");
+    assert!(buffer.move_to(3).is_ok());
+    assert!(!buffer.move_down());
+
+    buffer.delete_line(3).unwrap();
+    assert_eq!(buffer.cursor(), 2);
+    assert!(buffer.move_up());
+    assert_eq!(buffer.cursor(), 1);
+}
+
+#[test]
+fn save_and_load_round_trip() {
+    let path = env::temp_dir().join(format!("text_buffer_{}.txt", process::id()));
+    let mut buffer = TextBuffer::from_text("first\nsecond\n");
+
+    buffer.insert_line(1, "middle").unwrap();
+    buffer.save(&path).unwrap();
+
+    let loaded = TextBuffer::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded.line_count(), 3);
+    assert_eq!(loaded.contents(), "first\nmiddle\nsecond\n");
+}
+
+#[test]
+fn contents_round_trip_line_terminators() {
+    for text in [
+        "",
+        "\n",
+        "no newline",
+        "a\nb",
+        "a\nb\n",
+        "a\r\nb",
+        "a\r\nb\r\n",
+        "\r\n\r\n",
+        "ends with a bare\r",
+    ] {
+        assert_eq!(TextBuffer::from_text(text).contents(), text, "{:?}", text);
+    }
+}
+
+#[test]
+fn new_lines_use_the_loaded_terminator() {
+    let mut buffer = TextBuffer::from_text("one\r\ntwo");
+
+    buffer.insert_line(2, "three").unwrap();
+    buffer.split_line(0, 1).unwrap();
+    assert_eq!(buffer.contents(), "o\r\nne\r\ntwo\r\nthree");
+
+    buffer.undo();
+    buffer.undo();
+    assert_eq!(buffer.contents(), "one\r\ntwo");
+}
@@ -0,0 +1,182 @@
+use std::rc::Rc;
+
+use linked_list::conformance;
+use linked_list::linked_list::ListError;
+use linked_list::unrolled_list::UnrolledList;
+
+// Every node is non-empty and within capacity, the lengths add up, and
+// walking the `prev` links back from the tail meets the same nodes.
+fn check_nodes<const N: usize>(list: &UnrolledList<u32, N>) {
+    let mut total = 0;
+    for node in list.nodes() {
+        let node = node.borrow();
+        assert!(!node.is_empty(), "empty node left in the list");
+        assert!(node.len() <= N, "node over capacity");
+        total += node.len();
+    }
+    assert_eq!(total, list.len() as usize);
+
+    let forward: Vec<_> = list.nodes().map(|node| Rc::as_ptr(&node)).collect();
+    let mut backward: Vec<_> = list.nodes().rev().map(|node| Rc::as_ptr(&node)).collect();
+    backward.reverse();
+    assert_eq!(forward, backward, "prev links do not match the next links");
+}
+
+#[test]
+fn conforms_to_vec_deque() {
+    conformance::check_all::<UnrolledList<u32>>();
+    conformance::check_all::<UnrolledList<u32, 2>>();
+    conformance::check_all::<UnrolledList<u32, 5>>();
+}
+
+#[test]
+fn appends_fill_nodes_before_linking_new_ones() {
+    let mut list: UnrolledList<u32, 4> = UnrolledList::default();
+    for value in 0..10 {
+        list.append(value);
+    }
+
+    let lengths: Vec<usize> = list.nodes().map(|node| node.borrow().len()).collect();
+    assert_eq!(lengths, vec![4, 4, 2]);
+    assert_eq!(list.to_vec(), (0..10).collect::<Vec<_>>());
+    check_nodes(&list);
+}
+
+#[test]
+fn insert_into_a_full_node_splits_it() {
+    let mut list: UnrolledList<u32, 4> = UnrolledList::default();
+    for value in [0, 1, 2, 3] {
+        list.append(value);
+    }
+
+    list.insert_to(3, 9).unwrap();
+    let lengths: Vec<usize> = list.nodes().map(|node| node.borrow().len()).collect();
+    assert_eq!(lengths, vec![2, 3]);
+    assert_eq!(list.to_vec(), vec![0, 1, 2, 9, 3]);
+    check_nodes(&list);
+}
+
+#[test]
+fn removals_merge_sparse_nodes() {
+    let mut list: UnrolledList<u32, 4> = UnrolledList::default();
+    for value in 0..8 {
+        list.append(value);
+    }
+
+    assert_eq!(list.pop_at(0), Ok(0));
+    assert_eq!(list.pop_at(0), Ok(1));
+    assert_eq!(list.pop_at(0), Ok(2));
+    // Too full together to merge yet.
+    let lengths: Vec<usize> = list.nodes().map(|node| node.borrow().len()).collect();
+    assert_eq!(lengths, vec![1, 4]);
+
+    assert_eq!(list.pop_at(1), Ok(4));
+    assert_eq!(list.pop_at(1), Ok(5));
+    assert_eq!(list.pop_at(1), Ok(6));
+    let lengths: Vec<usize> = list.nodes().map(|node| node.borrow().len()).collect();
+    assert_eq!(lengths, vec![2]);
+    assert_eq!(list.to_vec(), vec![3, 7]);
+    check_nodes(&list);
+}
+
+#[test]
+fn index_errors_match_the_other_lists() {
+    let mut list: UnrolledList<u32> = UnrolledList::new();
+    assert_eq!(
+        list.insert_to(0, 1),
+        Err(ListError::IndexOutOfBounds { index: 0, len: 0 })
+    );
+    list.append(1);
+    assert_eq!(
+        list.pop_at(1),
+        Err(ListError::IndexOutOfBounds { index: 1, len: 1 })
+    );
+    assert_eq!(list.pop_last(), Some(1));
+    assert_eq!(list.pop_last(), None);
+}
+
+#[test]
+fn random_inserts_and_removals_match_a_vec() {
+    let mut list: UnrolledList<u32, 4> = UnrolledList::default();
+    let mut model = Vec::new();
+    let mut state = 0x2545_f491_u32;
+    let mut next = move |bound: u32| {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state % bound
+    };
+
+    for step in 0..3_000 {
+        let len = model.len() as u32;
+        match next(4) {
+            0 => {
+                list.append(step);
+                model.push(step);
+            }
+            1 | 2 if len > 0 => {
+                let index = next(len);
+                list.insert_to(index, step).unwrap();
+                model.insert(index as usize, step);
+            }
+            _ if len > 0 => {
+                let index = next(len);
+                assert_eq!(list.pop_at(index), Ok(model.remove(index as usize)));
+            }
+            _ => {}
+        }
+
+        if step % 50 == 0 {
+            assert_eq!(list.to_vec(), model);
+            check_nodes(&list);
+        }
+    }
+    assert_eq!(list.to_vec(), model);
+    check_nodes(&list);
+}
+
+#[test]
+fn long_lists_drop_without_recursing() {
+    let mut list: UnrolledList<u32, 2> = UnrolledList::default();
+    for value in 0..200_000 {
+        list.append(value);
+    }
+    drop(list);
+}
+
+#[test]
+fn iter_yields_element_references_in_order() {
+    let mut list: UnrolledList<String, 3> = UnrolledList::default();
+    for value in ["b", "c", "d", "e"] {
+        list.append(value.to_owned());
+    }
+    list.push_front("a".to_owned());
+    list.insert_to(3, "x".to_owned()).unwrap();
+
+    let values: Vec<String> = list.iter().map(|item| item.borrow().clone()).collect();
+    assert_eq!(values, vec!["a", "b", "c", "x", "d", "e"]);
+    assert_eq!(list.iter().count(), list.len() as usize);
+    assert!(UnrolledList::<u32>::new().iter().next().is_none());
+}
+
+#[test]
+fn inline_values_are_dropped_exactly_once() {
+    let value = Rc::new(0);
+    let mut list: UnrolledList<Rc<u32>, 4> = UnrolledList::default();
+    for _ in 0..20 {
+        list.append(Rc::clone(&value));
+    }
+    // Splits and merges move values between nodes without copying them.
+    for index in [3, 9, 15] {
+        list.insert_to(index, Rc::clone(&value)).unwrap();
+    }
+    for _ in 0..12 {
+        drop(list.pop_at(1).unwrap());
+    }
+    assert_eq!(list.len(), 11);
+    assert_eq!(Rc::strong_count(&value), 12);
+    assert!(list.iter().all(|item| Rc::ptr_eq(&item.borrow(), &value)));
+
+    drop(list);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
@@ -0,0 +1,97 @@
+use linked_list::linked_list::{DoublyLinkedList, ListError};
+
+fn numbers(count: u32) -> DoublyLinkedList<u32> {
+    (0..count).collect()
+}
+
+#[test]
+fn view_covers_only_its_range() {
+    let list = numbers(300);
+    let view = list.view(100..200).unwrap();
+
+    assert_eq!(view.len(), 100);
+    assert_eq!(*view.first().unwrap().borrow(), 100);
+    assert_eq!(*view.last().unwrap().borrow(), 199);
+    assert_eq!(view.to_vec(), (100..200).collect::<Vec<_>>());
+}
+
+#[test]
+fn iterates_from_both_ends() {
+    let list = numbers(10);
+    let view = list.view(2..=6).unwrap();
+
+    let backwards: Vec<u32> = view
+        .iter()
+        .rev()
+        .map(|node| node.borrow().value.unwrap())
+        .collect();
+    assert_eq!(backwards, vec![6, 5, 4, 3, 2]);
+
+    let mut iter = view.iter();
+    assert_eq!(iter.len(), 5);
+    assert_eq!(iter.next().unwrap().borrow().value, Some(2));
+    assert_eq!(iter.next_back().unwrap().borrow().value, Some(6));
+    let middle: Vec<u32> = iter.map(|node| node.borrow().value.unwrap()).collect();
+    assert_eq!(middle, vec![3, 4, 5]);
+}
+
+#[test]
+fn get_is_relative_to_the_view() {
+    let list = numbers(20);
+    let view = list.view(5..15).unwrap();
+
+    for index in 0..10 {
+        assert_eq!(*view.get(index).unwrap().borrow(), index + 5);
+    }
+    assert_eq!(
+        view.get(10).err(),
+        Some(ListError::IndexOutOfBounds { index: 10, len: 10 })
+    );
+}
+
+#[test]
+fn search_stays_inside_the_view() {
+    let list: DoublyLinkedList<u32> = vec![1, 2, 3, 1, 2, 3, 1].into();
+    let view = list.view(1..6).unwrap();
+
+    assert_eq!(view.position(|&value| value == 1), Some(2));
+    assert_eq!(view.rposition(|&value| value == 2), Some(3));
+    assert_eq!(view.position(|&value| value == 7), None);
+    assert!(view.contains(&3));
+    assert_eq!(
+        view.find_map(|&value| (value > 2).then_some(value * 10)),
+        Some(30)
+    );
+
+    let head = list.view(..1).unwrap();
+    assert!(!head.contains(&2));
+}
+
+#[test]
+fn empty_and_invalid_ranges() {
+    let list = numbers(5);
+
+    let empty = list.view(3..3).unwrap();
+    assert!(empty.is_empty());
+    assert_eq!(empty.iter().count(), 0);
+    assert!(empty.first().is_err());
+
+    assert!(list.view(..).unwrap().len() == 5);
+    assert_eq!(
+        list.view(2..9).err(),
+        Some(ListError::InvalidRange {
+            start: 2,
+            end: 9,
+            len: 5
+        })
+    );
+}
+
+#[test]
+fn several_views_can_share_a_list() {
+    let list = numbers(6);
+    let left = list.view(..3).unwrap();
+    let right = list.view(3..).unwrap();
+    assert_eq!(left.to_vec(), vec![0, 1, 2]);
+    assert_eq!(right.to_vec(), vec![3, 4, 5]);
+}
@@ -1,5 +1,18 @@
 use std::cell::RefCell;
-use std::rc::{Rc, Weak};
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+use linked_list::deque::Deque;
+use linked_list::diagnostics::{self, Diagnose, Diagnostics, DropWatch};
+use linked_list::linked_list::DoublyLinkedList as RcDoublyLinkedList;
+use linked_list::sentinel_list::SentinelList;
+
+// Both `prev` and `next` are strong `Rc`s, so every pair of neighbours is a
+// reference cycle. That is the point of this variant: it leaks on purpose,
+// as the counterpart to the main list's weak `prev`, and there is
+// deliberately no `Drop` to break the cycles. Popping an element unlinks it
+// and frees its node; dropping a list that still has nodes leaks all of
+// them. Built by the `refcell-strong-linkedlist` crate.
 
 pub struct Node<T> {
     pub value: Option<T>,
@@ -21,14 +34,12 @@ pub struct Iter<T> {
     pub current: Option<Rc<RefCell<Node<T>>>>,
 }
 
-impl<T: Clone> Iterator for Iter<T> {
+impl<T> Iterator for Iter<T> {
     type Item = Rc<RefCell<Node<T>>>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.current.take().map(|current| {
-            let next = current.borrow().next.clone();
-            self.current = next;
-            current
+        self.current.take().inspect(|current| {
+            self.current = current.borrow().next.clone();
         })
     }
 }
@@ -39,6 +50,12 @@ pub struct DoublyLinkedList<T> {
     tail: Option<Rc<RefCell<Node<T>>>>,
 }
 
+impl<T> Default for DoublyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> DoublyLinkedList<T> {
     pub fn new() -> Self {
         DoublyLinkedList {
@@ -48,14 +65,19 @@ impl<T> DoublyLinkedList<T> {
         }
     }
 
+    pub fn len(&self) -> u32 {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
     pub fn iter(&self) -> Iter<T> {
         Iter {
             current: self.head.clone(),
         }
     }
-}
-
-impl<T: std::fmt::Display> DoublyLinkedList<T> {
 
     // append function with RefCell
     pub fn append(&mut self, value: T) {
@@ -75,162 +97,292 @@ impl<T: std::fmt::Display> DoublyLinkedList<T> {
         self.count += 1;
     }
 
-// pop_last function with RefCell
-    pub fn pop_last(&mut self) -> Option<T> {
-        self.tail.take().map(|old_tail| {
-            if let Some(new_tail) = old_tail.borrow_mut().prev.take() {
-                new_tail.borrow_mut().next = None;
-                self.tail = Some(new_tail);
-            } else {
-                self.head.take();
+    pub fn push_front(&mut self, value: T) {
+        let new_node = Node::new(value);
+
+        match self.head.take() {
+            Some(old_head) => {
+                old_head.borrow_mut().prev = Some(Rc::clone(&new_node));
+                new_node.borrow_mut().next = Some(old_head);
             }
+            None => {
+                self.tail = Some(Rc::clone(&new_node));
+            }
+        }
 
-            self.count -= 1;
-            old_tail.borrow().value.take().unwrap()
-        })
+        self.head = Some(new_node);
+        self.count += 1;
+    }
+
+    // pop_last function with RefCell
+    pub fn pop_last(&mut self) -> Option<T> {
+        let old_tail = self.tail.clone()?;
+        Some(self.unlink(&old_tail))
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let old_head = self.head.clone()?;
+        Some(self.unlink(&old_head))
     }
 
     pub fn insert_to(&mut self, index: u32, value: T) -> Result<(), String> {
-        if index >= self.count {
-            return Err("Invalid index!".to_owned());
-        }
-        if index == 0 {
-            match self.head.take() {
-                Some(old_head) => {
-                    let new_node = Node::new(value);
-
-                    old_head.borrow_mut().prev = Some(Rc::downgrade(&new_node));
-                    new_node.borrow_mut().next = Some(old_head);
-
-                    self.head = Some(new_node);
-                }
-                None => {
-                    self.append(value);
-                }
-            }
-            Ok(())
-        } else {
-            let mut cursor = self.head.clone();
+        let next_node = self.node_at(index)?;
+        let prev = next_node.borrow_mut().prev.take();
+        let new_node = Node::new(value);
 
-            for _ in 0..index - 1 {
-                cursor = cursor.ok_or("Index out of bound!")?.borrow().next.clone();
-            }
+        new_node.borrow_mut().next = Some(Rc::clone(&next_node));
+        next_node.borrow_mut().prev = Some(Rc::clone(&new_node));
 
-            match cursor {
-                Some(current_node) => {
-                    let new_node = Node::new(value);
-
-                    match current_node.borrow_mut().next.take() {
-                        Some(new_next_node) => {
-                            new_node.borrow_mut().next = Some(Rc::clone(&new_next_node));
-                            new_next_node.borrow_mut().prev = Some(Rc::downgrade(&new_node));
-                        }
-                        _ => {}
-                    }
-
-                    current_node.borrow_mut().next = Some(new_node);
-                    self.count += 1;
-                }
-                None => {
-                    self.append(value);
-                }
+        match prev {
+            Some(prev_node) => {
+                prev_node.borrow_mut().next = Some(Rc::clone(&new_node));
+                new_node.borrow_mut().prev = Some(prev_node);
+            }
+            None => {
+                self.head = Some(new_node);
             }
-            Ok(())
         }
+
+        self.count += 1;
+        Ok(())
     }
 
     pub fn pop_at(&mut self, index: u32) -> Result<T, String> {
+        let node = self.node_at(index)?;
+        Ok(self.unlink(&node))
+    }
+
+    fn node_at(&self, index: u32) -> Result<Rc<RefCell<Node<T>>>, String> {
         if index >= self.count {
             return Err("Invalid index!".to_owned());
         }
 
-        if index == self.count - 1 {
-            return self.pop_last().ok_or("List is empty!".to_owned());
-        }
-
         let mut cursor = self.head.clone();
-
-        for _ in 0..=index - 1 {
+        for _ in 0..index {
             cursor = cursor.ok_or("Index out of bound!")?.borrow().next.clone();
         }
+        cursor.ok_or_else(|| "Index out of bound!".to_owned())
+    }
 
-        let current_rc = cursor.ok_or("Unexpected error: Missing current node!")?;
-        let mut current_node = current_rc.borrow_mut();
-
-        let prev = current_node
-            .prev
-            .take()
-            .and_then(|prev_weak| prev_weak.upgrade());
+    // Takes both links out of `node`, which drops the strong references it
+    // held on its neighbours, and joins the neighbours together.
+    fn unlink(&mut self, node: &Rc<RefCell<Node<T>>>) -> T {
+        let mut current_node = node.borrow_mut();
+        let prev = current_node.prev.take();
         let next = current_node.next.take();
 
-        if let Some(prev_node) = prev.clone() {
-            prev_node.borrow_mut().next = next.clone();
-        } else {
-            self.head = next.clone();
+        match &prev {
+            Some(prev_node) => prev_node.borrow_mut().next = next.clone(),
+            None => self.head = next.clone(),
         }
-
-        if let Some(next_node) = next {
-            next_node.borrow_mut().prev = prev.map(|node| Rc::downgrade(&node));
-        } else {
-            self.tail = prev.map(|node| Rc::downgrade(&node));
+        match &next {
+            Some(next_node) => next_node.borrow_mut().prev = prev,
+            None => self.tail = prev,
         }
 
         self.count -= 1;
+        current_node.value.take().expect("Node without a value")
+    }
+}
+
+impl<T> Deque for DoublyLinkedList<T> {
+    type Item = T;
+
+    fn push_front(&mut self, value: T) {
+        DoublyLinkedList::push_front(self, value);
+    }
+
+    fn push_back(&mut self, value: T) {
+        self.append(value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        DoublyLinkedList::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        self.pop_last()
+    }
+
+    fn peek_front_with<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        let head = self.head.as_ref()?.borrow();
+        Some(f(head.value.as_ref().expect("Node without a value")))
+    }
+
+    fn peek_back_with<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        let tail = self.tail.as_ref()?.borrow();
+        Some(f(tail.value.as_ref().expect("Node without a value")))
+    }
 
-        current_node
-            .value
-            .take()
-            .ok_or("Node without a value".to_owned())
+    fn len(&self) -> usize {
+        self.count as usize
     }
 }
 
-fn main() {
-    let mut list = DoublyLinkedList::new();
+// Popping takes every strong link along with the value, so the reference
+// cycles are broken as the list is consumed and nothing is leaked.
+pub struct IntoIter<T> {
+    list: DoublyLinkedList<T>,
+}
 
-    list.append(1);
-    list.append(2);
-    list.append(3);
-    println!("Appended 3 elements.");
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
 
-    for node in list.iter() {
-        println!("{}", node.borrow().value.unwrap());
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
     }
 
-    if let Some(value) = list.pop_last() {
-        println!("Popped last: {}", value);
-    } else {
-        println!("List was empty, nothing to pop.");
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.count as usize;
+        (len, Some(len))
     }
+}
 
-    if let Some(value) = list.pop_last() {
-        println!("Popped last: {}", value);
-    } else {
-        println!("List was empty, nothing to pop.");
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_last()
     }
+}
 
-    for node in list.iter() {
-        println!("{}", node.borrow().value.unwrap());
+impl<T> IntoIterator for DoublyLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
     }
+}
 
-    if let Some(value) = list.pop_last() {
-        println!("Popped last: {}", value);
-    } else {
-        println!("List was empty, nothing to pop.");
+impl<T> FromIterator<T> for DoublyLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = DoublyLinkedList::new();
+        for value in iter {
+            list.append(value);
+        }
+        list
     }
+}
 
-    list.append(4);
+impl<T> From<Vec<T>> for DoublyLinkedList<T> {
+    fn from(values: Vec<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T> From<VecDeque<T>> for DoublyLinkedList<T> {
+    fn from(values: VecDeque<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T> From<DoublyLinkedList<T>> for Vec<T> {
+    fn from(list: DoublyLinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> From<DoublyLinkedList<T>> for VecDeque<T> {
+    fn from(list: DoublyLinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+// Values are moved from one chain to the other; only the nodes are
+// reallocated, since the lists don't share a node layout.
+impl<T> From<RcDoublyLinkedList<T>> for DoublyLinkedList<T> {
+    fn from(list: RcDoublyLinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> From<DoublyLinkedList<T>> for RcDoublyLinkedList<T> {
+    fn from(list: DoublyLinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> From<SentinelList<T>> for DoublyLinkedList<T> {
+    fn from(list: SentinelList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> From<DoublyLinkedList<T>> for SentinelList<T> {
+    fn from(list: DoublyLinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+// Judged by the same rule as the weak-`prev` list: one owning pointer per
+// node (`head` or the previous node's `next`), plus `tail` on the last node.
+// Every `prev` here is a second owner, so each node but the tail is flagged
+// with the cycle that keeps it alive once the list is dropped.
+impl<T> Diagnose for DoublyLinkedList<T> {
+    type Node = Node<T>;
+
+    fn diagnostics(&self) -> Diagnostics {
+        let nodes = self
+            .iter()
+            .zip(0..)
+            .map(|(node, index)| {
+                let expected_strong = if index + 1 == self.count { 2 } else { 1 };
+                diagnostics::check_counts(index, &node, expected_strong, 0)
+            })
+            .collect();
+
+        Diagnostics {
+            name: "refcell-strong DoublyLinkedList",
+            nodes,
+        }
+    }
+
+    fn watch_nodes(&self) -> DropWatch<Node<T>> {
+        DropWatch::new(self.iter().map(|node| Rc::downgrade(&node)).collect())
+    }
+}
+
+impl<T: Clone> DoublyLinkedList<T> {
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter()
+            .map(|node| node.borrow().value.clone().expect("Node without a value"))
+            .collect()
+    }
+}
+
+fn main() {
+    let mut list = DoublyLinkedList::new();
+
+    list.append(1);
+    list.append(2);
+    list.append(3);
+    println!("Appended 3 elements.");
 
     for node in list.iter() {
         println!("{}", node.borrow().value.unwrap());
     }
 
-    if let Ok(_) = list.insert_to(0, 0) {
+    for _ in 0..2 {
+        match list.pop_last() {
+            Some(value) => println!("Popped last: {}", value),
+            None => println!("List was empty, nothing to pop."),
+        }
+    }
+
+    list.append(4);
+
+    if list.insert_to(0, 0).is_ok() {
         println!("Value inserted successfully!");
     } else {
         println!("Invalid index!");
     }
 
-    if let Ok(_) = list.insert_to(2, 5) {
+    if list.insert_to(2, 5).is_ok() {
         println!("Value inserted successfully!");
     } else {
         println!("Invalid index!");
@@ -238,30 +390,133 @@ fn main() {
 
     list.append(12);
     list.append(13);
+    println!("{:?}", list.to_vec());
 
-    for node in list.iter() {
-        println!("{}", node.borrow().value.unwrap());
+    match list.pop_at(1) {
+        Ok(value) => println!("Popped at index 1: {}", value),
+        Err(error) => println!("{}", error),
     }
 
-    if let Ok(value) = list.pop_at(1) {
-        println!("Popped at index 1: {}", value);
-    } else {
-        println!("Invalid index or list was empty.");
+    print!("{}", list.diagnostics());
+
+    // Emptying the list through the conversion frees every node; just
+    // dropping `list` here would leak them.
+    println!("{:?}", Vec::from(list));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use linked_list::conformance::{self, Subject};
+    use linked_list::diagnostics::{self, Diagnose};
+    use linked_list::linked_list::DoublyLinkedList as RcDoublyLinkedList;
+    use linked_list::sentinel_list::SentinelList;
+
+    use super::DoublyLinkedList;
+
+    // The suite drops lists that still hold elements, so this test leaks
+    // their nodes; that is the behaviour under study, not a test bug.
+    impl Subject for DoublyLinkedList<u32> {
+        fn with_capacity(_capacity: usize) -> Self {
+            DoublyLinkedList::new()
+        }
+
+        fn capacity(&self) -> Option<usize> {
+            None
+        }
+
+        fn get(&self, index: usize) -> Option<u32> {
+            self.iter().nth(index).and_then(|node| node.borrow().value)
+        }
     }
 
-    for node in list.iter() {
-        println!("{}", node.borrow().value.unwrap());
+    #[test]
+    fn conforms_to_vec_deque() {
+        conformance::check_all::<DoublyLinkedList<u32>>();
+    }
+
+    #[test]
+    fn insert_to_links_both_directions() {
+        let mut list: DoublyLinkedList<u32> = vec![1, 3].into();
+
+        list.insert_to(1, 2).unwrap();
+        list.insert_to(0, 0).unwrap();
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.to_vec(), vec![0, 1, 2, 3]);
+
+        let backwards: Vec<_> = std::iter::from_fn(|| list.pop_last()).collect();
+        assert_eq!(backwards, vec![3, 2, 1, 0]);
+        assert_eq!(list.insert_to(0, 9), Err("Invalid index!".to_owned()));
     }
 
-    // let mut list2 = DoublyLinkedList::new();
+    #[test]
+    fn strong_prev_links_are_reported_as_a_leak() {
+        let list: DoublyLinkedList<u32> = (0..4).collect();
+
+        let report = list.diagnostics();
+        assert!(!report.is_healthy());
+        assert_eq!(
+            report.flagged().map(|node| node.index).collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+        assert!(report
+            .flagged()
+            .all(|node| node.strong == 2 && node.issues[0].contains("cycle")));
+
+        let watch = list.watch_nodes();
+        drop(list);
+        assert_eq!(watch.alive(), vec![0, 1, 2, 3]);
+        assert_eq!(diagnostics::leaked_values::<DoublyLinkedList<_>>(4), 4);
+    }
 
-    // list2.append("hello");
+    #[test]
+    fn single_node_and_emptied_lists_do_not_leak() {
+        let list: DoublyLinkedList<u32> = vec![1].into();
+        assert!(list.diagnostics().is_healthy());
+        diagnostics::assert_drop_frees_all(list);
 
-    let x = Box::new("hello");
+        let list: DoublyLinkedList<u32> = (0..4).collect();
+        let watch = list.watch_nodes();
+        assert_eq!(Vec::from(list), vec![0, 1, 2, 3]);
+        watch.assert_all_freed();
+    }
 
-    println!("{}", x);
+    #[test]
+    fn converts_to_and_from_the_weak_prev_list() {
+        let list = DoublyLinkedList::from(RcDoublyLinkedList::from(vec![1, 2, 3]));
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
 
-    let y = &x;
+        let back = RcDoublyLinkedList::from(list);
+        assert_eq!(back.to_vec(), vec![1, 2, 3]);
+        assert!(back.diagnostics().is_healthy());
+    }
 
-    println!("{}", *y);
+    #[test]
+    fn converts_to_and_from_the_sentinel_list() {
+        let list = DoublyLinkedList::from(SentinelList::from(vec![1, 2, 3]));
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+
+        let back = SentinelList::from(list);
+        assert_eq!(Vec::from(back), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn conversions_move_values_without_leaking() {
+        let value = Rc::new(0);
+        let list: DoublyLinkedList<_> = (0..10).map(|_| Rc::clone(&value)).collect();
+        assert_eq!(Rc::strong_count(&value), 11);
+
+        let mut list = DoublyLinkedList::from(RcDoublyLinkedList::from(list));
+        assert_eq!(
+            list.pop_last().map(|last| Rc::ptr_eq(&last, &value)),
+            Some(true)
+        );
+        assert_eq!(Rc::strong_count(&value), 10);
+
+        let values = Vec::from(list);
+        assert_eq!(values.len(), 9);
+        drop(values);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
 }
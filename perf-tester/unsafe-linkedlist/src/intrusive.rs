@@ -0,0 +1,389 @@
+use std::cell::Cell;
+use std::marker::{PhantomData, PhantomPinned};
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// An intrusive doubly linked list: the list allocates nothing, the prev/next
+// pointers live in a `Links` field inside the objects themselves. An object
+// with several `Links` fields can be on several lists at once, one per
+// field, each list picking its field through an `Adapter`.
+//
+// Objects are handed in as `Pin<&'a T>`. `Links` is `!Unpin`, so a linked
+// object can't be moved, and the `'a` borrow keeps it alive and in place
+// for as long as the list exists.
+
+pub struct Links<T> {
+    prev: Cell<Option<NonNull<T>>>,
+    next: Cell<Option<NonNull<T>>>,
+    // Id of the list the object is on, or 0. Lets a list tell its own
+    // members apart and refuse objects that are already linked.
+    owner: Cell<usize>,
+    _pin: PhantomPinned,
+}
+
+impl<T> Links<T> {
+    pub const fn new() -> Self {
+        Links {
+            prev: Cell::new(None),
+            next: Cell::new(None),
+            owner: Cell::new(0),
+            _pin: PhantomPinned,
+        }
+    }
+
+    pub fn is_linked(&self) -> bool {
+        self.owner.get() != 0
+    }
+}
+
+impl<T> Default for Links<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Picks which `Links` field of `Object` a list uses.
+///
+/// # Safety
+///
+/// `links` must always return the same field of the object it is given, and
+/// no other adapter may return that field.
+pub unsafe trait Adapter {
+    type Object;
+
+    fn links(object: &Self::Object) -> &Links<Self::Object>;
+}
+
+static NEXT_LIST_ID: AtomicUsize = AtomicUsize::new(1);
+
+pub struct IntrusiveList<'a, A: Adapter> {
+    id: usize,
+    head: Option<NonNull<A::Object>>,
+    tail: Option<NonNull<A::Object>>,
+    len: usize,
+    _objects: PhantomData<(&'a A::Object, A)>,
+}
+
+pub struct IntrusiveIter<'a, 'list, A: Adapter> {
+    current: Option<NonNull<A::Object>>,
+    _list: PhantomData<&'list IntrusiveList<'a, A>>,
+}
+
+impl<'a, A: Adapter> Iterator for IntrusiveIter<'a, '_, A> {
+    type Item = Pin<&'a A::Object>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.current?;
+        self.current = links_of::<A>(node).next.get();
+        Some(pinned::<A>(node))
+    }
+}
+
+impl<A: Adapter> Default for IntrusiveList<'_, A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, A: Adapter> IntrusiveList<'a, A> {
+    pub fn new() -> Self {
+        IntrusiveList {
+            id: NEXT_LIST_ID.fetch_add(1, Ordering::Relaxed),
+            head: None,
+            tail: None,
+            len: 0,
+            _objects: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn front(&self) -> Option<Pin<&'a A::Object>> {
+        self.head.map(pinned::<A>)
+    }
+
+    pub fn back(&self) -> Option<Pin<&'a A::Object>> {
+        self.tail.map(pinned::<A>)
+    }
+
+    pub fn iter(&self) -> IntrusiveIter<'a, '_, A> {
+        IntrusiveIter {
+            current: self.head,
+            _list: PhantomData,
+        }
+    }
+
+    // O(1): the object's own `Links` say which list it's on.
+    pub fn contains(&self, object: Pin<&A::Object>) -> bool {
+        A::links(&object).owner.get() == self.id
+    }
+
+    pub fn push_back(&mut self, object: Pin<&'a A::Object>) {
+        let node = self.claim(object);
+        let links = A::links(&object);
+        links.prev.set(self.tail);
+
+        match self.tail {
+            Some(tail) => links_of::<A>(tail).next.set(Some(node)),
+            None => self.head = Some(node),
+        }
+        self.tail = Some(node);
+        self.len += 1;
+    }
+
+    pub fn push_front(&mut self, object: Pin<&'a A::Object>) {
+        let node = self.claim(object);
+        let links = A::links(&object);
+        links.next.set(self.head);
+
+        match self.head {
+            Some(head) => links_of::<A>(head).prev.set(Some(node)),
+            None => self.tail = Some(node),
+        }
+        self.head = Some(node);
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<Pin<&'a A::Object>> {
+        let head = self.head?;
+        self.unlink(head);
+        Some(pinned::<A>(head))
+    }
+
+    pub fn pop_back(&mut self) -> Option<Pin<&'a A::Object>> {
+        let tail = self.tail?;
+        self.unlink(tail);
+        Some(pinned::<A>(tail))
+    }
+
+    // Takes `object` out of the list in O(1), through its own links.
+    // Returns false if it isn't on this list.
+    pub fn remove(&mut self, object: Pin<&'a A::Object>) -> bool {
+        if !self.contains(object) {
+            return false;
+        }
+        self.unlink(NonNull::from(object.get_ref()));
+        true
+    }
+
+    // Linking an object twice would tie two chains together through one
+    // pair of pointers. The check costs one load, and the safe API depends
+    // on it, so it stays on in release builds.
+    fn claim(&self, object: Pin<&'a A::Object>) -> NonNull<A::Object> {
+        let links = A::links(&object);
+        assert!(
+            !links.is_linked(),
+            "object is already linked into a list through this field"
+        );
+        links.owner.set(self.id);
+        links.prev.set(None);
+        links.next.set(None);
+        NonNull::from(object.get_ref())
+    }
+
+    fn unlink(&mut self, node: NonNull<A::Object>) {
+        let links = links_of::<A>(node);
+        let prev = links.prev.take();
+        let next = links.next.take();
+        links.owner.set(0);
+
+        match prev {
+            Some(prev) => links_of::<A>(prev).next.set(next),
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => links_of::<A>(next).prev.set(prev),
+            None => self.tail = prev,
+        }
+        self.len -= 1;
+    }
+}
+
+impl<A: Adapter> Drop for IntrusiveList<'_, A> {
+    // Unlinks every member, so the objects can go on other lists afterwards.
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+// Members are only ever added as `Pin<&'a Object>`, so every pointer in the
+// list refers to a pinned object that lives for at least 'a.
+fn pinned<'a, A: Adapter>(node: NonNull<A::Object>) -> Pin<&'a A::Object> {
+    unsafe { Pin::new_unchecked(node.as_ref()) }
+}
+
+fn links_of<'a, A: Adapter>(node: NonNull<A::Object>) -> &'a Links<A::Object> {
+    A::links(pinned::<A>(node).get_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::pin::pin;
+
+    use super::{links_of, Adapter, IntrusiveList, Links};
+
+    struct Timer {
+        deadline: u32,
+        by_deadline: Links<Timer>,
+        active: Links<Timer>,
+    }
+
+    impl Timer {
+        fn new(deadline: u32) -> Self {
+            Timer {
+                deadline,
+                by_deadline: Links::new(),
+                active: Links::new(),
+            }
+        }
+    }
+
+    struct ByDeadline;
+
+    unsafe impl Adapter for ByDeadline {
+        type Object = Timer;
+
+        fn links(timer: &Timer) -> &Links<Timer> {
+            &timer.by_deadline
+        }
+    }
+
+    struct Active;
+
+    unsafe impl Adapter for Active {
+        type Object = Timer;
+
+        fn links(timer: &Timer) -> &Links<Timer> {
+            &timer.active
+        }
+    }
+
+    // The deadlines front to back, after walking the list to check that each
+    // member points back at its neighbours and belongs to this list.
+    fn deadlines<A: Adapter<Object = Timer>>(list: &IntrusiveList<'_, A>) -> Vec<u32> {
+        let mut count = 0;
+        let mut prev = None;
+        let mut cursor = list.head;
+        while let Some(node) = cursor {
+            let links = links_of::<A>(node);
+            assert_eq!(links.owner.get(), list.id, "foreign object in the list");
+            assert_eq!(links.prev.get(), prev, "broken prev link");
+            count += 1;
+            assert!(count <= list.len, "list is longer than its length");
+            prev = Some(node);
+            cursor = links.next.get();
+        }
+        assert_eq!(prev, list.tail, "tail is not the last member");
+        assert_eq!(count, list.len, "list is shorter than its length");
+
+        list.iter().map(|timer| timer.deadline).collect()
+    }
+
+    #[test]
+    fn push_pop_both_ends() {
+        let a = pin!(Timer::new(1));
+        let b = pin!(Timer::new(2));
+        let c = pin!(Timer::new(3));
+        let mut list = IntrusiveList::<ByDeadline>::new();
+
+        list.push_back(a.as_ref());
+        list.push_back(b.as_ref());
+        list.push_front(c.as_ref());
+        assert_eq!(deadlines(&list), vec![3, 1, 2]);
+        assert_eq!(list.len(), 3);
+
+        assert_eq!(list.pop_front().map(|timer| timer.deadline), Some(3));
+        assert_eq!(list.pop_back().map(|timer| timer.deadline), Some(2));
+        assert_eq!(list.front().map(|timer| timer.deadline), Some(1));
+        assert_eq!(list.back().map(|timer| timer.deadline), Some(1));
+        assert!(!c.by_deadline.is_linked());
+    }
+
+    #[test]
+    fn objects_can_be_on_several_lists() {
+        let timers = [
+            Box::pin(Timer::new(10)),
+            Box::pin(Timer::new(20)),
+            Box::pin(Timer::new(30)),
+        ];
+        let mut by_deadline = IntrusiveList::<ByDeadline>::new();
+        let mut active = IntrusiveList::<Active>::new();
+
+        for timer in &timers {
+            by_deadline.push_back(timer.as_ref());
+            active.push_front(timer.as_ref());
+        }
+        assert_eq!(deadlines(&by_deadline), vec![10, 20, 30]);
+        assert_eq!(deadlines(&active), vec![30, 20, 10]);
+
+        assert!(active.remove(timers[1].as_ref()));
+        assert_eq!(deadlines(&active), vec![30, 10]);
+        assert_eq!(deadlines(&by_deadline), vec![10, 20, 30]);
+        assert!(by_deadline.contains(timers[1].as_ref()));
+        assert!(!active.contains(timers[1].as_ref()));
+    }
+
+    #[test]
+    fn remove_from_the_middle_and_the_ends() {
+        let timers: Vec<_> = (0..5).map(|i| Box::pin(Timer::new(i))).collect();
+        let mut list = IntrusiveList::<ByDeadline>::new();
+        for timer in &timers {
+            list.push_back(timer.as_ref());
+        }
+
+        assert!(list.remove(timers[2].as_ref()));
+        assert!(list.remove(timers[0].as_ref()));
+        assert!(list.remove(timers[4].as_ref()));
+        assert!(!list.remove(timers[4].as_ref()));
+        assert_eq!(deadlines(&list), vec![1, 3]);
+
+        // A removed object can be linked again.
+        list.push_front(timers[2].as_ref());
+        assert_eq!(deadlines(&list), vec![2, 1, 3]);
+    }
+
+    #[test]
+    fn remove_only_touches_its_own_list() {
+        let timer = Box::pin(Timer::new(1));
+        let mut first = IntrusiveList::<ByDeadline>::new();
+        let mut second = IntrusiveList::<ByDeadline>::new();
+
+        first.push_back(timer.as_ref());
+        assert!(!second.remove(timer.as_ref()));
+        assert_eq!(deadlines(&first), vec![1]);
+        assert!(second.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "already linked")]
+    fn linking_twice_panics() {
+        let timer = Box::pin(Timer::new(1));
+        let mut first = IntrusiveList::<ByDeadline>::new();
+        let mut second = IntrusiveList::<ByDeadline>::new();
+
+        first.push_back(timer.as_ref());
+        second.push_back(timer.as_ref());
+    }
+
+    #[test]
+    fn dropping_the_list_unlinks_its_members() {
+        let timer = Box::pin(Timer::new(1));
+        {
+            let mut list = IntrusiveList::<ByDeadline>::new();
+            list.push_back(timer.as_ref());
+            assert!(timer.by_deadline.is_linked());
+        }
+        assert!(!timer.by_deadline.is_linked());
+
+        let mut list = IntrusiveList::<ByDeadline>::new();
+        list.push_back(timer.as_ref());
+        assert_eq!(list.len(), 1);
+    }
+}
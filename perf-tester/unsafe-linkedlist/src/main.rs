@@ -1,4 +1,21 @@
-use std::ptr;
+mod intrusive;
+mod xor_list;
+
+use std::collections::VecDeque;
+use std::hint::black_box;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::time::Instant;
+use std::{mem, ptr};
+
+use linked_list::arena_list::ArenaList;
+use linked_list::deque::{Deque, Queue, Stack};
+use linked_list::linked_list::DoublyLinkedList as RcDoublyLinkedList;
+use linked_list::memory::{self, MemoryUsage};
+use linked_list::unrolled_list::UnrolledList;
+
+use intrusive::{Adapter, IntrusiveList, Links};
+use xor_list::XorList;
 
 struct Node<T> {
     data: T,
@@ -9,6 +26,13 @@ struct Node<T> {
 pub struct DoublyLinkedList<T> {
     head: *mut Node<T>,
     tail: *mut Node<T>,
+    len: usize,
+}
+
+impl<T> Default for DoublyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<T> DoublyLinkedList<T> {
@@ -16,9 +40,18 @@ impl<T> DoublyLinkedList<T> {
         DoublyLinkedList {
             head: ptr::null_mut(),
             tail: ptr::null_mut(),
+            len: 0,
         }
     }
 
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
     pub fn push_front(&mut self, data: T) {
         let new_node = Box::into_raw(Box::new(Node {
             data,
@@ -35,6 +68,7 @@ impl<T> DoublyLinkedList<T> {
         }
 
         self.head = new_node;
+        self.len += 1;
     }
 
     pub fn push_back(&mut self, data: T) {
@@ -53,6 +87,7 @@ impl<T> DoublyLinkedList<T> {
         }
 
         self.tail = new_node;
+        self.len += 1;
     }
 
     pub fn pop_front(&mut self) -> Option<T> {
@@ -70,6 +105,7 @@ impl<T> DoublyLinkedList<T> {
                 self.tail = ptr::null_mut();
             }
 
+            self.len -= 1;
             Some(old_head.data)
         }
     }
@@ -89,14 +125,197 @@ impl<T> DoublyLinkedList<T> {
                 self.head = ptr::null_mut();
             }
 
+            self.len -= 1;
             Some(old_tail.data)
         }
     }
+
+    // Every node is a single `Box<Node<T>>` allocation.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        let nodes = self.len;
+        let node_bytes = mem::size_of::<Node<T>>();
+        let breakdown = vec![
+            ("value", mem::size_of::<T>()),
+            ("prev: *mut Node", mem::size_of::<*mut Node<T>>()),
+            ("next: *mut Node", mem::size_of::<*mut Node<T>>()),
+        ];
+
+        MemoryUsage {
+            name: "unsafe DoublyLinkedList<T>".to_owned(),
+            elements: nodes,
+            allocated: nodes,
+            header_bytes: mem::size_of::<Self>(),
+            bytes_per_slot: node_bytes,
+            breakdown: memory::with_padding(breakdown, node_bytes),
+        }
+    }
+}
+
+impl<T> Deque for DoublyLinkedList<T> {
+    type Item = T;
+
+    fn push_front(&mut self, value: T) {
+        DoublyLinkedList::push_front(self, value);
+    }
+
+    fn push_back(&mut self, value: T) {
+        DoublyLinkedList::push_back(self, value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        DoublyLinkedList::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        DoublyLinkedList::pop_back(self)
+    }
+
+    fn peek_front_with<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        if self.head.is_null() {
+            return None;
+        }
+        unsafe { Some(f(&(*self.head).data)) }
+    }
+
+    fn peek_back_with<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        if self.tail.is_null() {
+            return None;
+        }
+        unsafe { Some(f(&(*self.tail).data)) }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+pub struct IntoIter<T> {
+    list: DoublyLinkedList<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> IntoIterator for DoublyLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+impl<T> FromIterator<T> for DoublyLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = DoublyLinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for DoublyLinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for DoublyLinkedList<T> {
+    fn from(values: Vec<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T> From<VecDeque<T>> for DoublyLinkedList<T> {
+    fn from(values: VecDeque<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T> From<DoublyLinkedList<T>> for Vec<T> {
+    fn from(list: DoublyLinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> From<DoublyLinkedList<T>> for VecDeque<T> {
+    fn from(list: DoublyLinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+// Values are moved from one chain to the other; only the nodes are
+// reallocated, since the two lists don't share a node layout.
+impl<T> From<RcDoublyLinkedList<T>> for DoublyLinkedList<T> {
+    fn from(list: RcDoublyLinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> From<DoublyLinkedList<T>> for RcDoublyLinkedList<T> {
+    fn from(list: DoublyLinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+pub struct Iter<'a, T> {
+    current: *mut Node<T>,
+    _list: PhantomData<&'a DoublyLinkedList<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current.is_null() {
+            return None;
+        }
+
+        // The list is borrowed for 'a, so no node can be freed meanwhile.
+        unsafe {
+            let node = &*self.current;
+            self.current = node.next;
+            Some(&node.data)
+        }
+    }
+}
+
+impl<T> DoublyLinkedList<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            current: self.head,
+            _list: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone> DoublyLinkedList<T> {
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
 }
 
 impl<T> Drop for DoublyLinkedList<T> {
     fn drop(&mut self) {
-        while let Some(_) = self.pop_front() {}
+        while self.pop_front().is_some() {}
     }
 }
 
@@ -110,4 +329,380 @@ fn main() {
     while let Some(value) = list.pop_front() {
         println!("{}", value);
     }
-}
\ No newline at end of file
+
+    let list = DoublyLinkedList::from(vec![1, 2, 3]);
+    println!("{:?}", list.to_vec());
+    let rc_list = RcDoublyLinkedList::from(list);
+    let list = DoublyLinkedList::from(rc_list);
+    println!("{:?}", Vec::from(list));
+
+    // The same code runs on either list; only the type parameter changes.
+    print_stack_and_queue::<DoublyLinkedList<u32>>();
+    print_stack_and_queue::<RcDoublyLinkedList<u32>>();
+    print_stack_and_queue::<XorList<u32>>();
+
+    let xor_list: XorList<u32> = (1..=5).collect();
+    let backwards: Vec<_> = xor_list.iter().rev().collect();
+    println!("{:?} reversed is {:?}", xor_list.to_vec(), backwards);
+
+    print_intrusive_connections();
+
+    print_memory_comparison(1000);
+    print_speed_comparison(100_000);
+}
+
+fn print_stack_and_queue<L: Deque<Item = u32> + Default>() {
+    let mut stack: Stack<L> = Stack::new();
+    let mut queue: Queue<L> = Queue::new();
+    for value in 1..=3 {
+        stack.push(value);
+        queue.push(value);
+    }
+    println!(
+        "stack top {:?}, queue front {:?}",
+        stack.peek(),
+        queue.peek()
+    );
+
+    let popped: Vec<_> = std::iter::from_fn(|| stack.pop()).collect();
+    let dequeued: Vec<_> = std::iter::from_fn(|| queue.pop()).collect();
+    println!("stack {:?}, queue {:?}", popped, dequeued);
+}
+
+// A connection sits on two intrusive lists at once: every open connection,
+// and the ones waiting to be written to. The connections are owned by the
+// `Vec`; the lists only borrow them.
+struct Connection {
+    id: u32,
+    open: Links<Connection>,
+    writable: Links<Connection>,
+}
+
+struct OpenConnections;
+
+unsafe impl Adapter for OpenConnections {
+    type Object = Connection;
+
+    fn links(connection: &Connection) -> &Links<Connection> {
+        &connection.open
+    }
+}
+
+struct WritableConnections;
+
+unsafe impl Adapter for WritableConnections {
+    type Object = Connection;
+
+    fn links(connection: &Connection) -> &Links<Connection> {
+        &connection.writable
+    }
+}
+
+fn print_intrusive_connections() {
+    let connections: Vec<Pin<Box<Connection>>> = (0..5)
+        .map(|id| {
+            Box::pin(Connection {
+                id,
+                open: Links::new(),
+                writable: Links::new(),
+            })
+        })
+        .collect();
+
+    let mut open = IntrusiveList::<OpenConnections>::new();
+    let mut writable = IntrusiveList::<WritableConnections>::new();
+    for connection in &connections {
+        open.push_back(connection.as_ref());
+        if connection.id % 2 == 0 {
+            writable.push_back(connection.as_ref());
+        }
+    }
+
+    // Closing a connection takes it off both lists in O(1), without
+    // searching either of them.
+    let closed = connections[2].as_ref();
+    open.remove(closed);
+    writable.remove(closed);
+
+    // Connection 3 has urgent data, so it goes to the front of the queue.
+    writable.push_front(connections[3].as_ref());
+
+    let ids: Vec<u32> = open.iter().map(|connection| connection.id).collect();
+    println!("open: {:?}", ids);
+    if let (Some(oldest), Some(newest)) = (open.front(), open.back()) {
+        println!("oldest open: {}, newest open: {}", oldest.id, newest.id);
+    }
+    while let Some(connection) = writable.pop_front() {
+        println!(
+            "writing to {} (still open: {})",
+            connection.id,
+            open.contains(connection)
+        );
+    }
+
+    // The newest connection is dropped first when the server sheds load.
+    if let Some(newest) = open.pop_back() {
+        println!("shed connection {}", newest.id);
+    }
+    println!(
+        "{} open, write queue empty: {}",
+        open.len(),
+        writable.is_empty()
+    );
+}
+
+fn print_memory_comparison(count: u64) {
+    let mut unsafe_list = DoublyLinkedList::new();
+    let mut rc_list = RcDoublyLinkedList::new();
+    let mut arena_list = ArenaList::new();
+    let mut xor_list = XorList::new();
+    let mut vec = Vec::new();
+    let mut deque = VecDeque::new();
+
+    for value in 0..count {
+        unsafe_list.push_back(value);
+        xor_list.push_back(value);
+        rc_list.append(value);
+        arena_list.append(value);
+        vec.push(value);
+        deque.push_back(value);
+    }
+
+    println!();
+    println!("Memory for {} u64 elements:", count);
+    memory::print_comparison(
+        &[
+            rc_list.memory_usage(),
+            unsafe_list.memory_usage(),
+            xor_list.memory_usage(),
+            arena_list.memory_usage(),
+            MemoryUsage::of_vec(&vec),
+            MemoryUsage::of_vec_deque(&deque),
+        ],
+        mem::size_of::<u64>(),
+    );
+}
+
+// Best of five runs, in nanoseconds per operation. Only meaningful with
+// `cargo run --release`.
+fn time_per_op<F: FnMut() -> u64>(mut f: F) -> f64 {
+    let mut best = f64::MAX;
+    for _ in 0..5 {
+        let start = Instant::now();
+        let ops = f();
+        best = best.min(start.elapsed().as_nanos() as f64 / ops as f64);
+    }
+    best
+}
+
+fn print_speed_comparison(count: u64) {
+    let unsafe_list: DoublyLinkedList<u64> = (0..count).collect();
+    let rc_list: RcDoublyLinkedList<u64> = (0..count).collect();
+    let xor_list: XorList<u64> = (0..count).collect();
+    let mut unrolled_list = UnrolledList::new();
+    for value in 0..count {
+        unrolled_list.append(value);
+    }
+    let mut arena_list = ArenaList::new();
+    for value in 0..count {
+        arena_list.append(value);
+    }
+    let vec: Vec<u64> = (0..count).collect();
+
+    let rows = [
+        (
+            "unsafe DoublyLinkedList<T>",
+            time_per_op(|| {
+                let mut list = DoublyLinkedList::new();
+                (0..count).for_each(|value| list.push_back(value));
+                while let Some(value) = list.pop_back() {
+                    black_box(value);
+                }
+                count * 2
+            }),
+            time_per_op(|| {
+                black_box(unsafe_list.iter().sum::<u64>());
+                count
+            }),
+        ),
+        (
+            "XorList<T>",
+            time_per_op(|| {
+                let mut list = XorList::new();
+                (0..count).for_each(|value| list.push_back(value));
+                while let Some(value) = list.pop_back() {
+                    black_box(value);
+                }
+                count * 2
+            }),
+            time_per_op(|| {
+                black_box(xor_list.iter().sum::<u64>());
+                count
+            }),
+        ),
+        (
+            "DoublyLinkedList<T>",
+            time_per_op(|| {
+                let mut list = RcDoublyLinkedList::new();
+                (0..count).for_each(|value| list.append(value));
+                while let Some(value) = list.pop_last() {
+                    black_box(value);
+                }
+                count * 2
+            }),
+            time_per_op(|| {
+                let sum: u64 = rc_list
+                    .iter()
+                    .map(|node| node.borrow().value.unwrap())
+                    .sum();
+                black_box(sum);
+                count
+            }),
+        ),
+        (
+            "UnrolledList<T>",
+            time_per_op(|| {
+                let mut list = UnrolledList::new();
+                (0..count).for_each(|value| list.append(value));
+                while let Some(value) = list.pop_last() {
+                    black_box(value);
+                }
+                count * 2
+            }),
+            time_per_op(|| {
+                let sum: u64 = unrolled_list.iter().map(|item| *item.borrow()).sum();
+                black_box(sum);
+                count
+            }),
+        ),
+        (
+            "ArenaList<T>",
+            time_per_op(|| {
+                let mut list = ArenaList::new();
+                (0..count).for_each(|value| {
+                    list.append(value);
+                });
+                while let Some(value) = list.pop_last() {
+                    black_box(value);
+                }
+                count * 2
+            }),
+            time_per_op(|| {
+                black_box(arena_list.iter().sum::<u64>());
+                count
+            }),
+        ),
+        (
+            "Vec<T>",
+            time_per_op(|| {
+                let mut vec = Vec::new();
+                (0..count).for_each(|value| vec.push(value));
+                while let Some(value) = vec.pop() {
+                    black_box(value);
+                }
+                count * 2
+            }),
+            time_per_op(|| {
+                black_box(vec.iter().sum::<u64>());
+                count
+            }),
+        ),
+    ];
+
+    println!();
+    println!("Speed for {} u64 elements (ns/op):", count);
+    println!(
+        "{:<28} {:>16} {:>10}",
+        "container", "push+pop back", "iterate"
+    );
+    for (name, push_pop, iterate) in rows {
+        println!("{:<28} {:>16.1} {:>10.2}", name, push_pop, iterate);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use linked_list::conformance::{self, Subject};
+    use linked_list::diagnostics;
+
+    use super::{DoublyLinkedList, RcDoublyLinkedList, XorList};
+
+    impl Subject for DoublyLinkedList<u32> {
+        fn with_capacity(_capacity: usize) -> Self {
+            DoublyLinkedList::new()
+        }
+
+        fn capacity(&self) -> Option<usize> {
+            None
+        }
+
+        fn get(&self, index: usize) -> Option<u32> {
+            self.iter().nth(index).copied()
+        }
+    }
+
+    #[test]
+    fn conforms_to_vec_deque() {
+        conformance::check_all::<DoublyLinkedList<u32>>();
+    }
+
+    #[test]
+    fn converts_from_and_into_vec() {
+        let list = DoublyLinkedList::from(vec![1, 2, 3]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(Vec::from(list), vec![1, 2, 3]);
+
+        let empty = DoublyLinkedList::<u32>::from(Vec::new());
+        assert!(empty.is_empty());
+        assert_eq!(Vec::from(empty), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn converts_from_and_into_vec_deque() {
+        let list = DoublyLinkedList::from(VecDeque::from(vec![4, 5]));
+        assert_eq!(list.to_vec(), vec![4, 5]);
+        assert_eq!(VecDeque::from(list), VecDeque::from(vec![4, 5]));
+    }
+
+    #[test]
+    fn collects_extends_and_iterates_from_both_ends() {
+        let mut list: DoublyLinkedList<u32> = (1..=3).collect();
+        list.extend([4, 5]);
+
+        let mut values = list.into_iter();
+        assert_eq!(values.next_back(), Some(5));
+        assert_eq!(values.next(), Some(1));
+        assert_eq!(values.collect::<Vec<_>>(), vec![2, 3, 4]);
+    }
+
+    // `Box` isn't `Copy` and the test never clones, so the values have to
+    // be moved from one list to the other.
+    #[test]
+    fn moves_values_to_and_from_the_rc_list() {
+        let list = DoublyLinkedList::from(vec![Box::new(1u32), Box::new(2)]);
+        let addresses: Vec<*const u32> = list.iter().map(|value| &**value as *const u32).collect();
+
+        let rc_list = RcDoublyLinkedList::from(list);
+        assert_eq!(rc_list.len(), 2);
+        let list = DoublyLinkedList::from(rc_list);
+
+        let moved: Vec<*const u32> = list.iter().map(|value| &**value as *const u32).collect();
+        assert_eq!(moved, addresses);
+        assert_eq!(Vec::from(list), vec![Box::new(1u32), Box::new(2)]);
+
+        let empty = DoublyLinkedList::from(RcDoublyLinkedList::<u32>::new());
+        assert!(empty.is_empty());
+    }
+
+    // Raw-pointer nodes have no counts to inspect, so these lists are
+    // checked by what they leave behind instead.
+    #[test]
+    fn dropping_frees_every_value() {
+        for len in [0, 1, 100] {
+            assert_eq!(diagnostics::leaked_values::<DoublyLinkedList<_>>(len), 0);
+            assert_eq!(diagnostics::leaked_values::<XorList<_>>(len), 0);
+        }
+    }
+}
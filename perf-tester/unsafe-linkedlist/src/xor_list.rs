@@ -0,0 +1,410 @@
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+use std::{mem, ptr};
+
+use linked_list::deque::Deque;
+use linked_list::memory::{self, MemoryUsage};
+
+// A doubly linked list with one link per node instead of two: `link` holds
+// the addresses of both neighbours XORed together, with null counting as 0.
+// Walking needs the node you came from, `next = link ^ prev`, which is why
+// the list keeps both ends and every walk starts at one of them.
+
+struct XorNode<T> {
+    data: T,
+    link: usize,
+}
+
+pub struct XorList<T> {
+    head: *mut XorNode<T>,
+    tail: *mut XorNode<T>,
+    len: usize,
+}
+
+// Addresses go through the exposed-provenance API so that a pointer rebuilt
+// from a XORed link is still allowed to access its node.
+fn addr<T>(node: *mut XorNode<T>) -> usize {
+    node.expose_provenance()
+}
+
+// The neighbour of `node` on the side away from `from`.
+unsafe fn step<T>(from: *mut XorNode<T>, node: *mut XorNode<T>) -> *mut XorNode<T> {
+    ptr::with_exposed_provenance_mut((*node).link ^ addr(from))
+}
+
+impl<T> Default for XorList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> XorList<T> {
+    pub fn new() -> Self {
+        XorList {
+            head: ptr::null_mut(),
+            tail: ptr::null_mut(),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Both ends look the same to a XOR list, so each operation is written
+    // once, for "the end" and "the other end".
+    pub fn push_front(&mut self, data: T) {
+        Self::push_at(&mut self.head, &mut self.tail, data);
+        self.len += 1;
+    }
+
+    pub fn push_back(&mut self, data: T) {
+        Self::push_at(&mut self.tail, &mut self.head, data);
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let data = Self::pop_at(&mut self.head, &mut self.tail)?;
+        self.len -= 1;
+        Some(data)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let data = Self::pop_at(&mut self.tail, &mut self.head)?;
+        self.len -= 1;
+        Some(data)
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        unsafe { self.head.as_ref().map(|node| &node.data) }
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        unsafe { self.tail.as_ref().map(|node| &node.data) }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            front: self.head,
+            before_front: ptr::null_mut(),
+            back: self.tail,
+            after_back: ptr::null_mut(),
+            remaining: self.len,
+            _list: PhantomData,
+        }
+    }
+
+    fn push_at(end: &mut *mut XorNode<T>, other_end: &mut *mut XorNode<T>, data: T) {
+        // The new node's only neighbour is the old end.
+        let node = Box::into_raw(Box::new(XorNode {
+            data,
+            link: addr(*end),
+        }));
+
+        if end.is_null() {
+            *other_end = node;
+        } else {
+            // The old end's link was `neighbour ^ 0`; adding the new node
+            // makes it `neighbour ^ node`.
+            unsafe {
+                (**end).link ^= addr(node);
+            }
+        }
+        *end = node;
+    }
+
+    fn pop_at(end: &mut *mut XorNode<T>, other_end: &mut *mut XorNode<T>) -> Option<T> {
+        if end.is_null() {
+            return None;
+        }
+
+        unsafe {
+            let old = *end;
+            let neighbour = step(ptr::null_mut(), old);
+
+            if neighbour.is_null() {
+                *other_end = ptr::null_mut();
+            } else {
+                (*neighbour).link ^= addr(old);
+            }
+            *end = neighbour;
+
+            Some(Box::from_raw(old).data)
+        }
+    }
+
+    // Every node is a single `Box<XorNode<T>>` allocation.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        let nodes = self.len;
+        let node_bytes = mem::size_of::<XorNode<T>>();
+        let breakdown = vec![
+            ("value", mem::size_of::<T>()),
+            ("link: prev ^ next", mem::size_of::<usize>()),
+        ];
+
+        MemoryUsage {
+            name: "XorList<T>".to_owned(),
+            elements: nodes,
+            allocated: nodes,
+            header_bytes: mem::size_of::<Self>(),
+            bytes_per_slot: node_bytes,
+            breakdown: memory::with_padding(breakdown, node_bytes),
+        }
+    }
+}
+
+impl<T: Clone> XorList<T> {
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T> Drop for XorList<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+// Each end of the walk carries the node just outside it, which is what the
+// next step is XORed against.
+pub struct Iter<'a, T> {
+    front: *mut XorNode<T>,
+    before_front: *mut XorNode<T>,
+    back: *mut XorNode<T>,
+    after_back: *mut XorNode<T>,
+    remaining: usize,
+    _list: PhantomData<&'a XorList<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        // The list is borrowed for 'a, so no node can be freed meanwhile.
+        unsafe {
+            let node = self.front;
+            self.front = step(self.before_front, node);
+            self.before_front = node;
+            Some(&(*node).data)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        unsafe {
+            let node = self.back;
+            self.back = step(self.after_back, node);
+            self.after_back = node;
+            Some(&(*node).data)
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+pub struct IntoIter<T> {
+    list: XorList<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for XorList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+impl<T> FromIterator<T> for XorList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = XorList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for XorList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for XorList<T> {
+    fn from(values: Vec<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T> From<VecDeque<T>> for XorList<T> {
+    fn from(values: VecDeque<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T> From<XorList<T>> for Vec<T> {
+    fn from(list: XorList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> Deque for XorList<T> {
+    type Item = T;
+
+    fn push_front(&mut self, value: T) {
+        XorList::push_front(self, value);
+    }
+
+    fn push_back(&mut self, value: T) {
+        XorList::push_back(self, value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        XorList::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        XorList::pop_back(self)
+    }
+
+    fn peek_front_with<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        self.front().map(f)
+    }
+
+    fn peek_back_with<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        self.back().map(f)
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use linked_list::conformance::{self, Subject};
+
+    use super::XorList;
+
+    impl Subject for XorList<u32> {
+        fn with_capacity(_capacity: usize) -> Self {
+            XorList::new()
+        }
+
+        fn capacity(&self) -> Option<usize> {
+            None
+        }
+
+        fn get(&self, index: usize) -> Option<u32> {
+            self.iter().nth(index).copied()
+        }
+    }
+
+    #[test]
+    fn conforms_to_vec_deque() {
+        conformance::check_all::<XorList<u32>>();
+    }
+
+    #[test]
+    fn iterates_in_both_directions() {
+        let list: XorList<u32> = (1..=5).collect();
+
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 4, 5]);
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![5, 4, 3, 2, 1]
+        );
+        assert_eq!(list.iter().len(), 5);
+    }
+
+    #[test]
+    fn iterator_ends_meet_in_the_middle() {
+        let list: XorList<u32> = (1..=5).collect();
+        let mut iter = list.iter();
+
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&5));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn into_iter_pops_from_both_ends() {
+        let list = XorList::from(vec![1, 2, 3, 4]);
+        let mut iter = list.into_iter();
+
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![2, 3]);
+    }
+
+    #[test]
+    fn drop_frees_every_value() {
+        let value = Rc::new(0);
+        let list: XorList<_> = (0..100).map(|_| Rc::clone(&value)).collect();
+        assert_eq!(Rc::strong_count(&value), 101);
+
+        drop(list);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn uses_one_link_per_node() {
+        let list: XorList<u64> = (0..10).collect();
+        let usage = list.memory_usage();
+
+        assert_eq!(usage.bytes_per_slot, 16);
+        assert_eq!(usage.total_bytes(), usage.header_bytes + 10 * 16);
+    }
+}